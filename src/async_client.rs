@@ -1,8 +1,9 @@
 use std::{
+    collections::HashMap,
     ffi::c_void,
     ptr, slice,
     sync::{Arc, Mutex},
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use open62541_sys::{
@@ -10,20 +11,34 @@ use open62541_sys::{
     UA_STATUSCODE_BADDISCONNECT,
 };
 use tokio::{
-    sync::oneshot,
+    sync::{mpsc, oneshot, watch},
     task::JoinHandle,
     time::{self, Instant, MissedTickBehavior},
 };
 
 use crate::{
-    ua, AsyncSubscription, CallbackOnce, ClientBuilder, DataType, Error, ServiceRequest,
-    ServiceResponse,
+    async_history, async_subscription, ua, AsyncSubscription, CallbackOnce, ClientBuilder,
+    DataType, Error, HistoryReadStream, ServiceRequest, ServiceResponse, SubscriptionOptions,
+    SubscriptionStatus,
 };
 
+/// Connection phase of an [`AsyncClient`], as reported by [`AsyncClient::state_changes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionPhase {
+    /// Client is connected and the session is established.
+    Connected,
+    /// Connection was lost and the background task is retrying with backoff.
+    Reconnecting,
+    /// Reconnection gave up after reaching the configured maximum number of attempts.
+    Failed,
+}
+
 /// Connected OPC UA client (with asynchronous API).
 pub struct AsyncClient {
     client: Arc<Mutex<ua::Client>>,
     background_handle: JoinHandle<()>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+    subscriptions: Arc<Mutex<HashMap<ua::SubscriptionId, mpsc::Sender<SubscriptionStatus>>>>,
 }
 
 impl AsyncClient {
@@ -46,10 +61,24 @@ impl AsyncClient {
             .into_async(cycle_time))
     }
 
-    pub(crate) fn from_sync(client: ua::Client, cycle_time: Duration) -> Self {
+    pub(crate) fn from_sync(
+        client: ua::Client,
+        cycle_time: Duration,
+        builder: ClientBuilder,
+        endpoint_url: String,
+    ) -> Self {
         let client = Arc::new(Mutex::new(client));
-
-        let background_task = background_task(Arc::clone(&client), cycle_time);
+        let subscriptions = Arc::new(Mutex::new(HashMap::new()));
+        let (state_tx, state_rx) = watch::channel(ConnectionPhase::Connected);
+
+        let background_task = background_task(
+            Arc::clone(&client),
+            cycle_time,
+            builder,
+            endpoint_url,
+            state_tx,
+            Arc::clone(&subscriptions),
+        );
         // Run the event loop concurrently. This may be a different thread when using tokio with
         // `rt-multi-thread`.
         let background_handle = tokio::spawn(background_task);
@@ -57,9 +86,20 @@ impl AsyncClient {
         Self {
             client,
             background_handle,
+            state_rx,
+            subscriptions,
         }
     }
 
+    /// Returns a channel that reports the client's [`ConnectionPhase`] over time.
+    ///
+    /// This allows reacting to connection loss and recovery, e.g. to pause writes while
+    /// reconnecting, independently of polling [`state()`](Self::state).
+    #[must_use]
+    pub fn state_changes(&self) -> watch::Receiver<ConnectionPhase> {
+        self.state_rx.clone()
+    }
+
     /// Gets current channel and session state, and connect status.
     ///
     /// # Errors
@@ -154,7 +194,7 @@ impl AsyncClient {
 
         let request = ua::ReadRequest::init().with_nodes_to_read(&nodes_to_read);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("read should return results"));
@@ -184,7 +224,7 @@ impl AsyncClient {
             .with_attribute_id(&attribute_id)
             .with_value(value)]);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("write should return results"));
@@ -217,7 +257,7 @@ impl AsyncClient {
                 .with_method_id(method_id)
                 .with_input_arguments(input_arguments)]);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("call should return results"));
@@ -248,7 +288,7 @@ impl AsyncClient {
         let request = ua::BrowseRequest::init()
             .with_nodes_to_browse(&[ua::BrowseDescription::default().with_node_id(node_id)]);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("browse should return results"));
@@ -289,7 +329,7 @@ impl AsyncClient {
 
         let request = ua::BrowseRequest::init().with_nodes_to_browse(&nodes_to_browse);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("browse should return results"));
@@ -332,7 +372,7 @@ impl AsyncClient {
     {
         let request = ua::BrowseNextRequest::init().with_continuation_points(continuation_points);
 
-        let response = service_request(&self.client, request).await?;
+        let response = service_request(&self.client, self.state_rx.clone(), request).await?;
 
         let Some(results) = response.results() else {
             return Err(Error::internal("browse should return results"));
@@ -354,13 +394,125 @@ impl AsyncClient {
         Ok(results)
     }
 
+    /// Reads historical raw values for a node over a time range.
+    ///
+    /// This returns a [`Stream`](futures_core::Stream) of result values rather than a single
+    /// response: internally, it transparently issues follow-up `HistoryRead` requests for as long
+    /// as the server returns a continuation point, so callers can iterate over arbitrarily long
+    /// time ranges by simply consuming the stream. Requests are issued by a background task that
+    /// starts as soon as the stream is created; it waits with the next request while the stream's
+    /// buffer of unconsumed values is full. Dropping the stream before it is exhausted releases the
+    /// outstanding continuation point on the server instead of leaking it.
+    ///
+    /// `num_values_per_node` limits the number of values returned per page (and thus per
+    /// individual `HistoryRead` request); use `0` to let the server decide.
+    ///
+    /// Errors (e.g. the node has no history, or the client is disconnected) are delivered as
+    /// stream items rather than returned directly, since requests happen in the background as the
+    /// stream is consumed.
+    #[must_use]
+    pub fn read_history_raw(
+        &self,
+        node_id: &ua::NodeId,
+        start: SystemTime,
+        end: SystemTime,
+        num_values_per_node: u32,
+    ) -> HistoryReadStream {
+        async_history::read_history_raw(
+            &self.client,
+            self.state_rx.clone(),
+            node_id.clone(),
+            start,
+            end,
+            num_values_per_node,
+        )
+    }
+
+    /// Reads historical aggregated values (e.g. average, minimum, or maximum) for a node over a
+    /// time range.
+    ///
+    /// `aggregate_type` is the node ID of the aggregate function to apply (one of the well-known
+    /// `AggregateFunction_*` node IDs defined by the OPC UA specification, e.g. `_Average`,
+    /// `_Minimum`, or `_Maximum`), and `processing_interval` is the step at which the server
+    /// computes aggregated values within the requested range.
+    ///
+    /// Like [`read_history_raw()`](Self::read_history_raw), this returns a
+    /// [`Stream`](futures_core::Stream) and transparently follows continuation points.
+    #[must_use]
+    pub fn read_history_aggregate(
+        &self,
+        node_id: &ua::NodeId,
+        start: SystemTime,
+        end: SystemTime,
+        aggregate_type: ua::NodeId,
+        processing_interval: Duration,
+    ) -> HistoryReadStream {
+        async_history::read_history_aggregate(
+            &self.client,
+            self.state_rx.clone(),
+            node_id.clone(),
+            start,
+            end,
+            aggregate_type,
+            processing_interval,
+        )
+    }
+
     /// Creates new [subscription](AsyncSubscription).
     ///
     /// # Errors
     ///
     /// This fails when the client is not connected.
     pub async fn create_subscription(&self) -> Result<AsyncSubscription, Error> {
-        AsyncSubscription::new(&self.client).await
+        let mut subscription = AsyncSubscription::new(&self.client).await?;
+        subscription.attach_owner_registry(&self.subscriptions);
+        Ok(subscription)
+    }
+
+    /// Creates new [subscription](AsyncSubscription) with the given publishing interval.
+    ///
+    /// This is a convenience shorthand for
+    /// [`create_subscription_with_config()`](Self::create_subscription_with_config) for the common
+    /// case where only the publishing interval needs to be customized. Use
+    /// [`create_subscription_with_config()`] directly for control over lifetime count, keep-alive
+    /// count, maximum notifications per publish, or priority as well.
+    ///
+    /// To receive data-change notifications for a node, call
+    /// [`AsyncSubscription::monitor()`](AsyncSubscription::monitor) on the returned subscription.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    ///
+    /// [`create_subscription_with_config()`]: Self::create_subscription_with_config
+    pub async fn create_subscription_with_publishing_interval(
+        &self,
+        publishing_interval: Duration,
+    ) -> Result<AsyncSubscription, Error> {
+        self.create_subscription_with_config(
+            &SubscriptionOptions::default().with_publishing_interval(publishing_interval),
+        )
+        .await
+    }
+
+    /// Creates new [subscription](AsyncSubscription) with custom parameters.
+    ///
+    /// Use this instead of [`create_subscription()`] when the default publishing interval,
+    /// lifetime count, keep-alive count, maximum notifications per publish, or priority are not
+    /// suitable, e.g. for slow polling or high-priority alarm subscriptions.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    ///
+    /// [`create_subscription()`]: Self::create_subscription
+    pub async fn create_subscription_with_config(
+        &self,
+        options: &SubscriptionOptions,
+    ) -> Result<AsyncSubscription, Error> {
+        let mut subscription = AsyncSubscription::new_with_config(&self.client, options).await?;
+        subscription.attach_owner_registry(&self.subscriptions);
+        Ok(subscription)
     }
 }
 
@@ -374,7 +526,14 @@ impl Drop for AsyncClient {
     }
 }
 
-async fn background_task(client: Arc<Mutex<ua::Client>>, cycle_time: Duration) {
+async fn background_task(
+    client: Arc<Mutex<ua::Client>>,
+    cycle_time: Duration,
+    builder: ClientBuilder,
+    endpoint_url: String,
+    state_tx: watch::Sender<ConnectionPhase>,
+    subscriptions: Arc<Mutex<HashMap<ua::SubscriptionId, mpsc::Sender<SubscriptionStatus>>>>,
+) {
     log::debug!("Starting background task");
 
     let mut interval = time::interval(cycle_time);
@@ -407,15 +566,35 @@ async fn background_task(client: Arc<Mutex<ua::Client>>, cycle_time: Duration) {
             // Context-sensitive handling of bad status codes.
             match status_code.into_raw() {
                 UA_STATUSCODE_BADDISCONNECT => {
-                    // Not an error.
-                    log::info!("Terminating background task after disconnect");
+                    log::info!("Connection lost, attempting to reconnect");
                 }
                 _ => {
                     // Unexpected error.
                     log::error!("Terminating background task: Run iterate failed with {error}");
+                    return;
                 }
             }
-            return;
+
+            if !reconnect_with_backoff(&client, &builder, &endpoint_url, &state_tx).await {
+                // Attempts exhausted: there is nothing more the background task can do.
+                return;
+            }
+
+            // Run the transfer on its own task: its requests are only answered through
+            // `UA_Client_run_iterate()`, which this loop must keep calling in the meantime.
+            tokio::spawn({
+                let client = Arc::clone(&client);
+                let subscriptions = Arc::clone(&subscriptions);
+                let state_rx = state_tx.subscribe();
+                async move { transfer_subscriptions(&client, &subscriptions, state_rx).await }
+            });
+
+            // Start the interval over, so that its schedule follows the end of the reconnection
+            // attempt rather than the ticks missed during it (its first tick finishes immediately).
+            interval = time::interval(cycle_time);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            continue;
         }
 
         let time_taken = start_of_cycle.elapsed();
@@ -430,8 +609,203 @@ async fn background_task(client: Arc<Mutex<ua::Client>>, cycle_time: Duration) {
     }
 }
 
-async fn service_request<R: ServiceRequest>(
+/// Reconnects with exponential backoff, updating `state_tx` along the way.
+///
+/// Returns `true` once reconnected, or `false` when `builder`'s `reconnect.max_attempts` is
+/// reached (or the client can no longer be locked) and the caller should give up.
+async fn reconnect_with_backoff(
+    client: &Mutex<ua::Client>,
+    builder: &ClientBuilder,
+    endpoint_url: &str,
+    state_tx: &watch::Sender<ConnectionPhase>,
+) -> bool {
+    let _unused = state_tx.send(ConnectionPhase::Reconnecting);
+
+    let mut delay = builder.reconnect.base_delay;
+    let mut attempt: u32 = 0;
+
+    loop {
+        attempt += 1;
+        if let Some(max_attempts) = builder.reconnect.max_attempts {
+            if attempt > max_attempts {
+                log::error!("Giving up reconnecting after {max_attempts} attempt(s)");
+                let _unused = state_tx.send(ConnectionPhase::Failed);
+                return false;
+            }
+        }
+
+        log::info!("Reconnecting (attempt {attempt})");
+
+        // Connect a brand new client on a blocking task instead of reconnecting the shared one
+        // while holding its mutex: `UA_Client_connect()` blocks on network I/O for as long as the
+        // handshake takes, and the background task's event loop above relies on that same mutex
+        // never being held longer than the zero-timeout `UA_Client_run_iterate()` call (so it
+        // doesn't block `service_request()` callers for the duration of a reconnect). Reconnecting
+        // always starts a new secure channel and session anyway, which is why
+        // `transfer_subscriptions()` exists.
+        let new_client = {
+            let builder = builder.clone();
+            let endpoint_url = endpoint_url.to_owned();
+            tokio::task::spawn_blocking(move || builder.connect(&endpoint_url)).await
+        };
+
+        match new_client {
+            Ok(Ok(new_client)) => {
+                let Ok(mut client) = client.lock() else {
+                    log::error!("Giving up reconnecting: client could not be locked");
+                    let _unused = state_tx.send(ConnectionPhase::Failed);
+                    return false;
+                };
+                *client = new_client.client;
+
+                log::info!("Reconnected successfully after {attempt} attempt(s)");
+                let _unused = state_tx.send(ConnectionPhase::Connected);
+                return true;
+            }
+            Ok(Err(error)) => {
+                log::warn!("Reconnect attempt {attempt} failed: {error}, retrying in {delay:?}");
+            }
+            Err(_join_error) => {
+                log::warn!("Reconnect attempt {attempt} task panicked, retrying in {delay:?}");
+            }
+        }
+
+        time::sleep(delay + jitter(delay)).await;
+
+        delay = delay.saturating_mul(2).min(builder.reconnect.max_delay);
+    }
+}
+
+/// Returns a small random fraction (up to 20%) of `delay`, to avoid reconnection storms when many
+/// clients lose their connection to the same server at the same time.
+fn jitter(delay: Duration) -> Duration {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let subsec_nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos());
+
+    delay.mul_f64(f64::from(subsec_nanos % 1000) / 1000.0 * 0.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::jitter;
+
+    #[test]
+    fn jitter_is_bounded_fraction_of_delay() {
+        let delay = Duration::from_secs(10);
+
+        // `jitter()` is sampled from current time, so exercise it more than once instead of
+        // asserting on a single, potentially unlucky sample.
+        for _ in 0..100 {
+            let jitter = jitter(delay);
+            assert!(jitter <= delay.mul_f64(0.2));
+        }
+    }
+
+    #[test]
+    fn jitter_of_zero_delay_is_zero() {
+        assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+    }
+}
+
+/// Attempts to transfer previously created subscriptions to the new session after a reconnect.
+///
+/// Transferring a subscription restores server-side publishing without recreating its monitored
+/// items. Subscriptions that the server has already discarded while disconnected (e.g. because
+/// their lifetime count was exceeded) cannot be transferred; recovering those would require
+/// recreating all of their monitored items from scratch, which is not attempted automatically.
+///
+/// For subscriptions that do transfer, any notifications the server reports as still available
+/// for retransmission (i.e. published while disconnected but not yet acknowledged) are recovered
+/// via `Republish` and delivered to the subscription as
+/// [`SubscriptionStatus::Recovered`](crate::SubscriptionStatus::Recovered).
+async fn transfer_subscriptions(
+    client: &Mutex<ua::Client>,
+    subscriptions: &Mutex<HashMap<ua::SubscriptionId, mpsc::Sender<SubscriptionStatus>>>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+) {
+    let subscriptions: Vec<_> = {
+        let Ok(subscriptions) = subscriptions.lock() else {
+            return;
+        };
+        subscriptions
+            .iter()
+            .map(|(subscription_id, status_sender)| (*subscription_id, status_sender.clone()))
+            .collect()
+    };
+
+    if subscriptions.is_empty() {
+        return;
+    }
+
+    let ids: Vec<_> = subscriptions
+        .iter()
+        .map(|(subscription_id, _)| *subscription_id)
+        .collect();
+
+    log::info!("Transferring {} subscription(s) to new session", ids.len());
+
+    let request = ua::TransferSubscriptionsRequest::init()
+        .with_subscription_ids(&ids)
+        .with_send_initial_values(true);
+
+    let response = match service_request(client, state_rx, request).await {
+        Ok(response) => response,
+        Err(error) => {
+            log::warn!("Failed to transfer subscriptions: {error}");
+            return;
+        }
+    };
+
+    let Some(results) = response.results() else {
+        log::warn!("Transfer subscriptions response did not contain results");
+        return;
+    };
+
+    for ((subscription_id, status_sender), result) in subscriptions.iter().zip(results.iter()) {
+        if Error::verify_good(&result.status_code()).is_err() {
+            log::warn!(
+                "Could not transfer subscription {subscription_id:?}: server has likely \
+                 discarded it while disconnected"
+            );
+            continue;
+        }
+
+        let available_sequence_numbers = result
+            .available_sequence_numbers()
+            .map(ua::Array::into_vec)
+            .unwrap_or_default();
+
+        if available_sequence_numbers.is_empty() {
+            continue;
+        }
+
+        log::info!(
+            "Recovering {} missed notification(s) for subscription {subscription_id:?} via \
+             Republish",
+            available_sequence_numbers.len()
+        );
+
+        let recovered = async_subscription::recover_missing_after_transfer(
+            client,
+            *subscription_id,
+            &available_sequence_numbers,
+        )
+        .await;
+
+        if !recovered.is_empty() {
+            let _unused = status_sender.try_send(SubscriptionStatus::Recovered(recovered));
+        }
+    }
+}
+
+pub(crate) async fn service_request<R: ServiceRequest>(
     client: &Mutex<ua::Client>,
+    mut state_rx: watch::Receiver<ConnectionPhase>,
     request: R,
 ) -> Result<R::Response, Error> {
     type Cb<R> = CallbackOnce<Result<<R as ServiceRequest>::Response, ua::StatusCode>>;
@@ -493,9 +867,35 @@ async fn service_request<R: ServiceRequest>(
     });
     Error::verify_good(&status_code)?;
 
-    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
-    // is only dropped after placing a value into the channel and `rx.await` always finds this value
-    // there.
-    rx.await
-        .unwrap_or(Err(Error::internal("callback should send result")))
+    // Besides waiting for `rx` (which `callback` above always resolves once the FFI callback
+    // fires), also race it against the client losing its connection: while reconnecting, the
+    // in-flight request's secure channel and session no longer exist, so the server will never
+    // answer it, and `rx` would otherwise hang until the caller gives up waiting or the process
+    // exits. Resolving it with a retryable error instead lets callers retry once reconnected.
+    tokio::select! {
+        result = rx => {
+            // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So
+            // the sender is only dropped after placing a value into the channel and this always
+            // finds a value there.
+            result.unwrap_or(Err(Error::internal("callback should send result")))
+        }
+        () = wait_for_disconnect(&mut state_rx) => {
+            Err(Error::retry("connection lost while waiting for response"))
+        }
+    }
+}
+
+/// Waits until `state_rx` no longer reports [`ConnectionPhase::Connected`].
+///
+/// Returns immediately if that is already the case.
+async fn wait_for_disconnect(state_rx: &mut watch::Receiver<ConnectionPhase>) {
+    loop {
+        if !matches!(*state_rx.borrow(), ConnectionPhase::Connected) {
+            return;
+        }
+        if state_rx.changed().await.is_err() {
+            // Sender was dropped (client is gone): there will never be a response either.
+            return;
+        }
+    }
 }
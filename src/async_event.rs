@@ -0,0 +1,340 @@
+use std::{
+    ffi::c_void,
+    pin::Pin,
+    ptr,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use futures_channel::oneshot;
+use futures_core::Stream;
+use open62541_sys::{
+    UA_Client, UA_Client_MonitoredItems_createEvent_async, UA_CreateMonitoredItemsResponse,
+    UA_UInt32, UA_Variant,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    async_monitored_item::{delete_monitored_item, slice_from_raw},
+    ua, CallbackOnce, DataType as _, Error,
+};
+
+/// Size of the buffer used to forward event notifications to consumers.
+///
+/// Once the buffer is full, the oldest (not yet consumed) notification is discarded to make room
+/// for the newest one, mirroring the backpressure used for data-change notifications (see
+/// [`AsyncMonitoredItem`](crate::AsyncMonitoredItem)): a slow consumer loses intermediate events
+/// but keeps seeing up-to-date ones.
+const NOTIFICATION_BUFFER_SIZE: usize = 16;
+
+/// One field to select from matching events, identified by a browse path relative to a type.
+///
+/// Use with [`EventFilter::with_select_clause()`]. See [`Self::base_event_field()`] for a
+/// convenient way to select well-known fields of `BaseEventType`.
+#[derive(Debug, Clone)]
+pub struct SimpleAttributeOperand {
+    type_id: ua::NodeId,
+    browse_path: Vec<ua::QualifiedName>,
+    attribute_id: ua::AttributeId,
+}
+
+impl SimpleAttributeOperand {
+    /// Creates operand selecting the value attribute at `browse_path`, relative to `type_id`.
+    #[must_use]
+    pub fn new(type_id: ua::NodeId, browse_path: Vec<ua::QualifiedName>) -> Self {
+        Self {
+            type_id,
+            browse_path,
+            attribute_id: ua::AttributeId::VALUE,
+        }
+    }
+
+    /// Sets the attribute to select (defaults to the value attribute).
+    #[must_use]
+    pub const fn with_attribute_id(mut self, attribute_id: ua::AttributeId) -> Self {
+        self.attribute_id = attribute_id;
+        self
+    }
+
+    /// Creates operand selecting `browse_name` (in namespace 0) relative to `BaseEventType`.
+    ///
+    /// This covers the common case of selecting one of the standard event fields, e.g.
+    /// `"EventType"`, `"SourceName"`, `"Message"`, or `"Severity"`.
+    #[must_use]
+    pub fn base_event_field(browse_name: &str) -> Self {
+        // `i=2041` is the well-known node ID of `BaseEventType`.
+        Self::new(
+            ua::NodeId::numeric(0, 2041),
+            vec![ua::QualifiedName::new(0, browse_name)],
+        )
+    }
+
+    fn as_raw(&self) -> ua::SimpleAttributeOperand {
+        ua::SimpleAttributeOperand::init()
+            .with_type_definition_id(&self.type_id)
+            .with_browse_path(&self.browse_path)
+            .with_attribute_id(&self.attribute_id)
+    }
+}
+
+/// Filter selecting which events are reported and which of their fields are delivered.
+///
+/// Use with [`AsyncSubscription::monitor_events()`](crate::AsyncSubscription::monitor_events). Each
+/// select clause becomes one element of the `Vec<ua::Variant>` rows yielded by the returned
+/// [`EventStream`], in the same order.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    select_clauses: Vec<SimpleAttributeOperand>,
+    where_clause: Option<ua::ContentFilter>,
+}
+
+impl EventFilter {
+    /// Adds a field to select from matching events.
+    #[must_use]
+    pub fn with_select_clause(mut self, operand: SimpleAttributeOperand) -> Self {
+        self.select_clauses.push(operand);
+        self
+    }
+
+    /// Sets the where-clause that determines which events are reported.
+    ///
+    /// Without a where-clause, the server reports all events of the monitored node.
+    #[must_use]
+    pub fn with_where_clause(mut self, where_clause: ua::ContentFilter) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+
+    fn as_raw(&self) -> ua::EventFilter {
+        let select_clauses: Vec<_> =
+            self.select_clauses.iter().map(SimpleAttributeOperand::as_raw).collect();
+
+        let filter = ua::EventFilter::init().with_select_clauses(&select_clauses);
+
+        match &self.where_clause {
+            Some(where_clause) => filter.with_where_clause(where_clause),
+            None => filter,
+        }
+    }
+}
+
+/// Context passed to the FFI event callback for the lifetime of an event monitored item.
+///
+/// This is allocated once when the monitored item is created and freed when it is dropped, after
+/// the delete request for the monitored item has been issued to the server.
+struct EventContext {
+    sender: mpsc::Sender<Vec<ua::Variant>>,
+}
+
+unsafe extern "C" fn event_callback_c(
+    _client: *mut UA_Client,
+    _sub_id: UA_UInt32,
+    _sub_context: *mut c_void,
+    _mon_id: UA_UInt32,
+    mon_context: *mut c_void,
+    num_event_fields: usize,
+    event_fields: *mut UA_Variant,
+) {
+    log::debug!("Event notification received");
+
+    // SAFETY: `mon_context` is the pointer we passed in when creating the monitored item, and it
+    // stays valid (and unique to this monitored item) for as long as the item exists.
+    let context = unsafe { mon_context.cast::<EventContext>().as_ref() }
+        .expect("monitored item context should be set");
+
+    // SAFETY: `event_fields` is valid for `num_event_fields` elements for the duration of the
+    // callback.
+    let fields = unsafe { slice_from_raw(event_fields, num_event_fields) };
+    let fields: Vec<_> = fields.iter().map(ua::Variant::clone_raw).collect();
+
+    // Apply discard-oldest backpressure, mirroring the data-change callback: if the buffer is
+    // full, drop the oldest pending notification to make room for this one.
+    if let Err(mpsc::error::TrySendError::Full(fields)) = context.sender.try_send(fields) {
+        let _unused = context.sender.try_recv();
+        let _unused = context.sender.try_send(fields);
+    }
+}
+
+/// Stream of event notifications for a single monitored node.
+///
+/// Returned by [`AsyncSubscription::monitor_events()`](crate::AsyncSubscription::monitor_events).
+/// Each item is one row of field values, decoded from the server's `EventFieldList` and ordered to
+/// match the [`EventFilter`]'s select clauses.
+///
+/// Dropping the stream deletes the underlying monitored item on the server; dropping the
+/// [`AsyncSubscription`](crate::AsyncSubscription) it was created from deletes all of its
+/// monitored items, including this one.
+pub struct EventStream {
+    client: Weak<Mutex<ua::Client>>,
+    subscription_id: ua::SubscriptionId,
+    monitored_item_id: ua::MonitoredItemId,
+    receiver: mpsc::Receiver<Vec<ua::Variant>>,
+    // Kept alive for as long as the monitored item exists: the event callback holds a raw pointer
+    // into this allocation. Never read directly, but dropping it early would leave the callback
+    // with a dangling context pointer.
+    _context: Box<EventContext>,
+}
+
+impl EventStream {
+    pub(crate) async fn new(
+        client: &Arc<Mutex<ua::Client>>,
+        subscription_id: &ua::SubscriptionId,
+        node_id: &ua::NodeId,
+        filter: &EventFilter,
+    ) -> Result<Self, Error> {
+        let item_to_create = Self::as_request(node_id, filter);
+
+        let (sender, receiver) = mpsc::channel(NOTIFICATION_BUFFER_SIZE);
+        let mut context = Box::new(EventContext { sender });
+
+        let monitored_item_id = create_event_monitored_item(
+            client,
+            subscription_id,
+            item_to_create,
+            ptr::from_mut(context.as_mut()).cast::<c_void>(),
+        )
+        .await?;
+
+        Ok(Self {
+            client: Arc::downgrade(client),
+            subscription_id: *subscription_id,
+            monitored_item_id,
+            receiver,
+            _context: context,
+        })
+    }
+
+    /// Returns the monitored item ID assigned by the server.
+    #[must_use]
+    pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.monitored_item_id
+    }
+
+    /// Builds the (owned) monitored-item creation request.
+    ///
+    /// Note that the result must be converted to its raw representation via `to_raw_copy()`
+    /// immediately before the synchronous FFI call that consumes it, in the same scope: the raw
+    /// struct only borrows the heap buffers (select clauses, where-clause, ...) owned by this
+    /// value, so it must not outlive it.
+    fn as_request(node_id: &ua::NodeId, filter: &EventFilter) -> ua::MonitoredItemCreateRequest {
+        let parameters = ua::MonitoringParameters::init()
+            .with_queue_size(1)
+            .with_discard_oldest(true)
+            .with_filter(&filter.as_raw());
+
+        let item_to_monitor = ua::ReadValueId::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::EVENT_NOTIFIER);
+
+        ua::MonitoredItemCreateRequest::init()
+            .with_item_to_monitor(&item_to_monitor)
+            .with_monitoring_mode(ua::MonitoringMode::REPORTING)
+            .with_requested_parameters(&parameters)
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Vec<ua::Variant>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+
+        delete_monitored_item(&client, &self.subscription_id, &self.monitored_item_id);
+    }
+}
+
+async fn create_event_monitored_item(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    item_to_create: ua::MonitoredItemCreateRequest,
+    mon_context: *mut c_void,
+) -> Result<ua::MonitoredItemId, Error> {
+    type Cb = CallbackOnce<Result<ua::MonitoredItemId, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("MonitoredItems_createEvent() completed");
+
+        let response = response.cast::<UA_CreateMonitoredItemsResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            // PANIC: We expect the server to return exactly one result for our single request.
+            let results = unsafe { slice_from_raw(response.results, response.resultsSize) };
+            let result = results.first().expect("response should contain a result");
+            let result_status = ua::StatusCode::new(result.statusCode);
+
+            if result_status.is_good() {
+                Ok(ua::MonitoredItemId::new(result.monitoredItemId))
+            } else {
+                Err(result_status)
+            }
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<ua::MonitoredItemId, Error>>();
+
+    let callback = |result: Result<ua::MonitoredItemId, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been canceled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    let status_code = ua::StatusCode::new({
+        let Ok(mut client) = client.lock() else {
+            return Err(Error::internal("should be able to lock client"));
+        };
+
+        log::debug!("Calling MonitoredItems_createEvent()");
+
+        // SAFETY: The request is converted to its raw representation right before the call, which
+        // takes it by value and does not take ownership; `item_to_create` (the owned request)
+        // stays alive until this block ends, keeping the raw struct's borrowed pointers valid.
+        let item_to_create = unsafe { ua::MonitoredItemCreateRequest::to_raw_copy(&item_to_create) };
+
+        unsafe {
+            UA_Client_MonitoredItems_createEvent_async(
+                client.as_mut_ptr(),
+                subscription_id.as_raw(),
+                open62541_sys::UA_TimestampsToReturn::UA_TIMESTAMPSTORETURN_BOTH,
+                item_to_create,
+                mon_context,
+                Some(event_callback_c),
+                None,
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    rx.await
+        .unwrap_or(Err(Error::internal("callback should send result")))
+}
@@ -0,0 +1,231 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+
+use futures_core::Stream;
+use tokio::sync::{mpsc, watch};
+
+use crate::{async_client::service_request, ua, ConnectionPhase, DataType as _, Error};
+
+/// Number of history values buffered between the background paging task and the stream consumer.
+///
+/// Unlike the discard-oldest buffers used for live notifications (see
+/// [`AsyncMonitoredItem`](crate::AsyncMonitoredItem)), this channel applies ordinary backpressure:
+/// a slow consumer simply delays the next `HistoryRead` request rather than losing values, since
+/// historical data cannot be "caught up on" the way live updates can.
+const HISTORY_BUFFER_SIZE: usize = 16;
+
+/// Stream of historical data values, either raw or aggregated.
+///
+/// Returned by [`AsyncClient::read_history_raw()`](crate::AsyncClient::read_history_raw) and
+/// [`AsyncClient::read_history_aggregate()`](crate::AsyncClient::read_history_aggregate). See
+/// the former for details on paging and continuation-point handling.
+pub struct HistoryReadStream {
+    receiver: mpsc::Receiver<Result<ua::DataValue, Error>>,
+}
+
+impl Stream for HistoryReadStream {
+    type Item = Result<ua::DataValue, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+pub(crate) fn read_history_raw(
+    client: &Arc<Mutex<ua::Client>>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+    node_id: ua::NodeId,
+    start: SystemTime,
+    end: SystemTime,
+    num_values_per_node: u32,
+) -> HistoryReadStream {
+    let (sender, receiver) = mpsc::channel(HISTORY_BUFFER_SIZE);
+    let client = Arc::downgrade(client);
+
+    let Ok(start_time) = ua::DateTime::try_from(start) else {
+        return invalid_time_range_stream(receiver, sender);
+    };
+    let Ok(end_time) = ua::DateTime::try_from(end) else {
+        return invalid_time_range_stream(receiver, sender);
+    };
+
+    let details = ua::ReadRawModifiedDetails::init()
+        .with_start_time(&start_time)
+        .with_end_time(&end_time)
+        .with_num_values_per_node(num_values_per_node)
+        .with_is_read_modified(false);
+
+    tokio::spawn(page_history(
+        client,
+        state_rx,
+        node_id,
+        ua::ExtensionObject::new(&details),
+        sender,
+    ));
+
+    HistoryReadStream { receiver }
+}
+
+/// Reads aggregated historical values (e.g. average, minimum, or maximum) for `node_id` over
+/// `[start, end]`, computed by the server at `processing_interval` steps.
+///
+/// `aggregate_type` is the node ID of the aggregate function to apply, e.g. the well-known
+/// `AggregateFunction_Average`, `_Minimum`, or `_Maximum` node IDs defined by the OPC UA
+/// specification. Like [`read_history_raw()`], continuation points are followed transparently.
+pub(crate) fn read_history_aggregate(
+    client: &Arc<Mutex<ua::Client>>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+    node_id: ua::NodeId,
+    start: SystemTime,
+    end: SystemTime,
+    aggregate_type: ua::NodeId,
+    processing_interval: Duration,
+) -> HistoryReadStream {
+    let (sender, receiver) = mpsc::channel(HISTORY_BUFFER_SIZE);
+    let client = Arc::downgrade(client);
+
+    let Ok(start_time) = ua::DateTime::try_from(start) else {
+        return invalid_time_range_stream(receiver, sender);
+    };
+    let Ok(end_time) = ua::DateTime::try_from(end) else {
+        return invalid_time_range_stream(receiver, sender);
+    };
+
+    let details = ua::ReadProcessedDetails::init()
+        .with_start_time(&start_time)
+        .with_end_time(&end_time)
+        .with_processing_interval(processing_interval.as_secs_f64() * 1000.0)
+        .with_aggregate_type(&[aggregate_type]);
+
+    tokio::spawn(page_history(
+        client,
+        state_rx,
+        node_id,
+        ua::ExtensionObject::new(&details),
+        sender,
+    ));
+
+    HistoryReadStream { receiver }
+}
+
+/// Returns a stream that immediately yields a single "invalid time range" error.
+///
+/// Used when `start` or `end` cannot be converted to a [`ua::DateTime`] before any request is
+/// sent, so callers still learn about the problem through the stream instead of a panic.
+fn invalid_time_range_stream(
+    receiver: mpsc::Receiver<Result<ua::DataValue, Error>>,
+    sender: mpsc::Sender<Result<ua::DataValue, Error>>,
+) -> HistoryReadStream {
+    tokio::spawn(async move {
+        let _unused = sender
+            .send(Err(Error::internal("invalid history time range")))
+            .await;
+    });
+
+    HistoryReadStream { receiver }
+}
+
+/// Issues `HistoryRead` requests, forwarding each result value to `sender`, until the server stops
+/// returning a continuation point, the client is dropped, or the consumer drops the stream.
+async fn page_history(
+    client: Weak<Mutex<ua::Client>>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+    node_id: ua::NodeId,
+    details: ua::ExtensionObject,
+    sender: mpsc::Sender<Result<ua::DataValue, Error>>,
+) {
+    let mut continuation_point = ua::ContinuationPoint::init();
+
+    loop {
+        let Some(client) = client.upgrade() else {
+            return;
+        };
+
+        let request = ua::HistoryReadRequest::init()
+            .with_history_read_details(&details)
+            .with_timestamps_to_return(
+                open62541_sys::UA_TimestampsToReturn::UA_TIMESTAMPSTORETURN_BOTH,
+            )
+            .with_nodes_to_read(&[ua::HistoryReadValueId::init()
+                .with_node_id(&node_id)
+                .with_continuation_point(&continuation_point)]);
+
+        let response = match service_request(&client, state_rx.clone(), request).await {
+            Ok(response) => response,
+            Err(error) => {
+                let _unused = sender.send(Err(error)).await;
+                return;
+            }
+        };
+
+        let Some(results) = response.results() else {
+            let _unused = sender
+                .send(Err(Error::internal("history read should return results")))
+                .await;
+            return;
+        };
+        let Some(result) = results.as_slice().first() else {
+            let _unused = sender
+                .send(Err(Error::internal("history read should return a result")))
+                .await;
+            return;
+        };
+
+        if let Err(error) = Error::verify_good(&result.status_code()) {
+            let _unused = sender.send(Err(error)).await;
+            return;
+        }
+
+        let Some(history_data) = result.history_data() else {
+            return;
+        };
+
+        for value in history_data.into_vec() {
+            if sender.send(Ok(value)).await.is_err() {
+                // The consumer dropped the stream: release the continuation point (if any)
+                // instead of leaking it, and stop paging through history nobody will read.
+                if let Some(continuation_point) = result.continuation_point() {
+                    release_continuation_point(&client, state_rx.clone(), &node_id, &continuation_point)
+                        .await;
+                }
+                return;
+            }
+        }
+
+        let Some(next_continuation_point) = result.continuation_point() else {
+            return;
+        };
+
+        if sender.is_closed() {
+            // The consumer dropped the stream. Unlike the `sender.send()` case above, this can
+            // also happen when `history_data` was empty (e.g. the page covered a gap with no
+            // values): the `for` loop above never runs then, so it alone cannot detect a dropped
+            // consumer and we would otherwise keep paging through history forever.
+            release_continuation_point(&client, state_rx.clone(), &node_id, &next_continuation_point)
+                .await;
+            return;
+        }
+
+        continuation_point = next_continuation_point;
+    }
+}
+
+/// Releases a `HistoryRead` continuation point that the caller no longer intends to follow up on.
+async fn release_continuation_point(
+    client: &Mutex<ua::Client>,
+    state_rx: watch::Receiver<ConnectionPhase>,
+    node_id: &ua::NodeId,
+    continuation_point: &ua::ContinuationPoint,
+) {
+    let request = ua::HistoryReadRequest::init()
+        .with_release_continuation_points(true)
+        .with_nodes_to_read(&[ua::HistoryReadValueId::init()
+            .with_node_id(node_id)
+            .with_continuation_point(continuation_point)]);
+
+    let _unused = service_request(client, state_rx, request).await;
+}
@@ -0,0 +1,801 @@
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    pin::Pin,
+    ptr,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_channel::oneshot;
+use futures_core::Stream;
+use open62541_sys::{
+    UA_Client, UA_Client_MonitoredItems_createDataChange_async,
+    UA_Client_MonitoredItems_createDataChanges_async, UA_Client_MonitoredItems_delete_async,
+    UA_CreateMonitoredItemsResponse, UA_DataValue, UA_UInt32,
+};
+use tokio::sync::mpsc;
+
+use crate::{ua, CallbackOnce, DataType as _, Error};
+
+/// Size of the buffer used to forward data-change notifications to consumers.
+///
+/// Once the buffer is full, the oldest (not yet consumed) notification is discarded to make room
+/// for the newest one. This keeps a slow consumer from causing unbounded memory growth, at the
+/// cost of losing intermediate values (the most recent value is always eventually delivered).
+const NOTIFICATION_BUFFER_SIZE: usize = 16;
+
+/// Context passed to the FFI data-change callback for the lifetime of a monitored item.
+///
+/// This is allocated once when the monitored item is created and freed when it is dropped, after
+/// the delete request for the monitored item has been issued to the server.
+struct DataChangeContext {
+    sender: mpsc::Sender<ua::DataValue>,
+}
+
+unsafe extern "C" fn data_change_callback_c(
+    _client: *mut UA_Client,
+    _sub_id: UA_UInt32,
+    _sub_context: *mut c_void,
+    _mon_id: UA_UInt32,
+    mon_context: *mut c_void,
+    value: *mut UA_DataValue,
+) {
+    log::debug!("Data change notification received");
+
+    // SAFETY: `mon_context` is the pointer we passed in when creating the monitored item, and it
+    // stays valid (and unique to this monitored item) for as long as the item exists.
+    let context = unsafe { mon_context.cast::<DataChangeContext>().as_ref() }
+        .expect("monitored item context should be set");
+
+    // SAFETY: Incoming pointer is valid for access for the duration of the callback.
+    let Some(value) = (unsafe { value.as_ref() }) else {
+        return;
+    };
+    let value = ua::DataValue::clone_raw(value);
+
+    // Apply discard-oldest backpressure: if the buffer is full, drop the oldest pending
+    // notification to make room for this one, rather than blocking the event loop or leaking
+    // memory by growing without bound.
+    if let Err(mpsc::error::TrySendError::Full(value)) = context.sender.try_send(value) {
+        let _unused = context.sender.try_recv();
+        let _unused = context.sender.try_send(value);
+    }
+}
+
+/// Trigger for reporting a data change notification.
+///
+/// This controls which parts of a changed [`ua::DataValue`] cause the server to send a
+/// notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataChangeTrigger {
+    /// Only the status is monitored.
+    Status,
+    /// Status and value are monitored (this is the OPC UA default).
+    #[default]
+    StatusValue,
+    /// Status, value, and source timestamp are monitored.
+    StatusValueTimestamp,
+}
+
+impl DataChangeTrigger {
+    const fn as_raw(self) -> open62541_sys::UA_DataChangeTrigger {
+        match self {
+            Self::Status => open62541_sys::UA_DataChangeTrigger::UA_DATACHANGETRIGGER_STATUS,
+            Self::StatusValue => {
+                open62541_sys::UA_DataChangeTrigger::UA_DATACHANGETRIGGER_STATUSVALUE
+            }
+            Self::StatusValueTimestamp => {
+                open62541_sys::UA_DataChangeTrigger::UA_DATACHANGETRIGGER_STATUSVALUETIMESTAMP
+            }
+        }
+    }
+}
+
+/// Deadband for a [`DataChangeFilter`].
+///
+/// A deadband suppresses notifications for value changes that are too small to be of interest,
+/// reducing traffic between server and client.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Deadband {
+    /// Every value change is reported.
+    #[default]
+    None,
+    /// Only report changes that exceed the given absolute value.
+    Absolute(f64),
+    /// Only report changes that exceed the given percentage of the node's instrument range.
+    Percent(f64),
+}
+
+/// Filter that suppresses uninteresting data-change notifications.
+///
+/// Use this with [`MonitoringParameters`] to reduce the number of notifications sent by the
+/// server, e.g. by ignoring changes smaller than some threshold.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DataChangeFilter {
+    trigger: DataChangeTrigger,
+    deadband: Deadband,
+}
+
+impl DataChangeFilter {
+    /// Sets the trigger that decides which parts of the value are monitored.
+    #[must_use]
+    pub const fn with_trigger(mut self, trigger: DataChangeTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Sets the deadband that suppresses small value changes.
+    #[must_use]
+    pub const fn with_deadband(mut self, deadband: Deadband) -> Self {
+        self.deadband = deadband;
+        self
+    }
+
+    fn as_raw(self) -> ua::DataChangeFilter {
+        let (deadband_type, deadband_value) = match self.deadband {
+            Deadband::None => (open62541_sys::UA_DeadbandType::UA_DEADBANDTYPE_NONE, 0.0),
+            Deadband::Absolute(value) => {
+                (open62541_sys::UA_DeadbandType::UA_DEADBANDTYPE_ABSOLUTE, value)
+            }
+            Deadband::Percent(value) => {
+                (open62541_sys::UA_DeadbandType::UA_DEADBANDTYPE_PERCENT, value)
+            }
+        };
+
+        ua::DataChangeFilter::init()
+            .with_trigger(self.trigger.as_raw())
+            .with_deadband_type(deadband_type as u32)
+            .with_deadband_value(deadband_value)
+    }
+}
+
+/// Parameters for creating a [monitored item](AsyncMonitoredItem).
+///
+/// The default values match the `open62541` library default, i.e. monitoring the node's value
+/// attribute with reporting enabled and no deadband filtering.
+#[derive(Debug, Clone)]
+pub struct MonitoringParameters {
+    attribute_id: ua::AttributeId,
+    sampling_interval: Duration,
+    queue_size: u32,
+    discard_oldest: bool,
+    monitoring_mode: ua::MonitoringMode,
+    filter: Option<DataChangeFilter>,
+}
+
+impl Default for MonitoringParameters {
+    fn default() -> Self {
+        Self {
+            attribute_id: ua::AttributeId::VALUE,
+            sampling_interval: Duration::ZERO,
+            queue_size: 1,
+            discard_oldest: true,
+            monitoring_mode: ua::MonitoringMode::REPORTING,
+            filter: None,
+        }
+    }
+}
+
+impl MonitoringParameters {
+    /// Sets the node attribute to monitor (defaults to the value attribute).
+    #[must_use]
+    pub const fn with_attribute_id(mut self, attribute_id: ua::AttributeId) -> Self {
+        self.attribute_id = attribute_id;
+        self
+    }
+
+    /// Sets the requested sampling interval.
+    ///
+    /// A duration of zero requests the fastest practical sampling rate.
+    #[must_use]
+    pub const fn with_sampling_interval(mut self, sampling_interval: Duration) -> Self {
+        self.sampling_interval = sampling_interval;
+        self
+    }
+
+    /// Sets the size of the server-side notification queue.
+    #[must_use]
+    pub const fn with_queue_size(mut self, queue_size: u32) -> Self {
+        self.queue_size = queue_size;
+        self
+    }
+
+    /// Sets whether the oldest or the newest notification is discarded when the queue is full.
+    #[must_use]
+    pub const fn with_discard_oldest(mut self, discard_oldest: bool) -> Self {
+        self.discard_oldest = discard_oldest;
+        self
+    }
+
+    /// Sets the monitoring mode (disabled, sampling, or reporting).
+    #[must_use]
+    pub const fn with_monitoring_mode(mut self, monitoring_mode: ua::MonitoringMode) -> Self {
+        self.monitoring_mode = monitoring_mode;
+        self
+    }
+
+    /// Sets the data-change filter used to suppress uninteresting notifications.
+    #[must_use]
+    pub const fn with_filter(mut self, filter: DataChangeFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Builds the (owned) monitored-item creation request.
+    ///
+    /// Note that the result must be converted to its raw representation via `to_raw_copy()`
+    /// immediately before the synchronous FFI call that consumes it, in the same scope: the raw
+    /// struct only borrows the heap buffers (node ID, filter, ...) owned by this value, so it must
+    /// not outlive it.
+    pub(crate) fn as_request(&self, node_id: &ua::NodeId) -> ua::MonitoredItemCreateRequest {
+        let mut parameters = ua::MonitoringParameters::init()
+            .with_sampling_interval(self.sampling_interval.as_secs_f64() * 1000.0)
+            .with_queue_size(self.queue_size)
+            .with_discard_oldest(self.discard_oldest);
+
+        if let Some(filter) = self.filter {
+            parameters = parameters.with_filter(&filter.as_raw());
+        }
+
+        let item_to_create = ua::ReadValueId::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&self.attribute_id);
+
+        ua::MonitoredItemCreateRequest::init()
+            .with_item_to_monitor(&item_to_create)
+            .with_monitoring_mode(self.monitoring_mode)
+            .with_requested_parameters(&parameters)
+    }
+}
+
+/// Specification for one item in a batch of monitored items.
+///
+/// Used with [`AsyncSubscription::create_monitored_items()`](crate::AsyncSubscription::create_monitored_items).
+#[derive(Debug, Clone)]
+pub struct MonitoredItemSpec {
+    node_id: ua::NodeId,
+    parameters: MonitoringParameters,
+}
+
+impl MonitoredItemSpec {
+    /// Creates specification with default [`MonitoringParameters`].
+    #[must_use]
+    pub fn new(node_id: ua::NodeId) -> Self {
+        Self::with_parameters(node_id, MonitoringParameters::default())
+    }
+
+    /// Creates specification with custom [`MonitoringParameters`].
+    #[must_use]
+    pub const fn with_parameters(node_id: ua::NodeId, parameters: MonitoringParameters) -> Self {
+        Self {
+            node_id,
+            parameters,
+        }
+    }
+}
+
+/// Registry of live monitored items, keyed by server-assigned ID.
+///
+/// Owned by [`AsyncSubscription`](crate::AsyncSubscription) so that creating monitored items can
+/// detect duplicate registrations (the server returning an ID that is already tracked). Dropping
+/// the subscription clears it; the monitored items themselves are deleted on the server along
+/// with the subscription.
+pub(crate) type MonitoredItemRegistry = Arc<Mutex<HashMap<ua::MonitoredItemId, ()>>>;
+
+/// Monitored item (with asynchronous API).
+///
+/// Create this with [`AsyncSubscription::create_monitored_item()`](crate::AsyncSubscription::create_monitored_item)
+/// or [`AsyncSubscription::create_monitored_items()`](crate::AsyncSubscription::create_monitored_items).
+pub struct AsyncMonitoredItem {
+    client: Weak<Mutex<ua::Client>>,
+    subscription_id: ua::SubscriptionId,
+    monitored_item_id: ua::MonitoredItemId,
+    registry: Option<Weak<Mutex<HashMap<ua::MonitoredItemId, ()>>>>,
+    receiver: mpsc::Receiver<ua::DataValue>,
+    // Kept alive for as long as the monitored item exists: the data-change callback holds a raw
+    // pointer into this allocation. Never read directly, but dropping it early would leave the
+    // callback with a dangling context pointer.
+    _context: Box<DataChangeContext>,
+}
+
+impl AsyncMonitoredItem {
+    pub(crate) async fn new(
+        client: &Arc<Mutex<ua::Client>>,
+        subscription_id: &ua::SubscriptionId,
+        node_id: &ua::NodeId,
+    ) -> Result<Self, Error> {
+        Self::new_with_config(
+            client,
+            subscription_id,
+            node_id,
+            &MonitoringParameters::default(),
+        )
+        .await
+    }
+
+    pub(crate) async fn new_with_config(
+        client: &Arc<Mutex<ua::Client>>,
+        subscription_id: &ua::SubscriptionId,
+        node_id: &ua::NodeId,
+        parameters: &MonitoringParameters,
+    ) -> Result<Self, Error> {
+        Self::new_registered(client, subscription_id, node_id, parameters, None).await
+    }
+
+    pub(crate) async fn new_registered(
+        client: &Arc<Mutex<ua::Client>>,
+        subscription_id: &ua::SubscriptionId,
+        node_id: &ua::NodeId,
+        parameters: &MonitoringParameters,
+        registry: Option<&MonitoredItemRegistry>,
+    ) -> Result<Self, Error> {
+        let item_to_create = parameters.as_request(node_id);
+
+        let (sender, receiver) = mpsc::channel(NOTIFICATION_BUFFER_SIZE);
+        let mut context = Box::new(DataChangeContext { sender });
+
+        let monitored_item_id = create_monitored_item(
+            client,
+            subscription_id,
+            item_to_create,
+            ptr::from_mut(context.as_mut()).cast::<c_void>(),
+        )
+        .await?;
+
+        if let Some(registry) = registry {
+            let is_duplicate = registry
+                .lock()
+                .map_or(false, |mut items| items.insert(monitored_item_id, ()).is_some());
+            if is_duplicate {
+                log::warn!(
+                    "Server returned already-registered monitored item ID {monitored_item_id:?}"
+                );
+            }
+        }
+
+        Ok(AsyncMonitoredItem {
+            client: Arc::downgrade(client),
+            subscription_id: *subscription_id,
+            monitored_item_id,
+            registry: registry.map(Arc::downgrade),
+            receiver,
+            _context: context,
+        })
+    }
+
+    /// Returns the monitored item ID assigned by the server.
+    #[must_use]
+    pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.monitored_item_id
+    }
+
+    /// Returns a [`Stream`] of data-change notifications for this monitored item.
+    ///
+    /// This is equivalent to using [`AsyncMonitoredItem`] directly as a [`Stream`] (it implements
+    /// [`Stream`] itself), and is provided for callers who prefer an explicit method call.
+    pub fn notifications(&mut self) -> impl Stream<Item = ua::DataValue> + '_ {
+        self
+    }
+}
+
+impl Stream for AsyncMonitoredItem {
+    type Item = ua::DataValue;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Stream of data-change notifications for a single node.
+///
+/// Returned by [`AsyncSubscription::monitor()`](crate::AsyncSubscription::monitor) and
+/// [`AsyncSubscription::monitor_with_config()`](crate::AsyncSubscription::monitor_with_config).
+/// This is a thin, named wrapper around [`AsyncMonitoredItem`] used as a [`Stream`] (use
+/// [`AsyncMonitoredItem`] directly when access to the monitored item ID is also needed).
+///
+/// Dropping the stream deletes the underlying monitored item on the server; dropping the
+/// [`AsyncSubscription`](crate::AsyncSubscription) it was created from deletes all of its
+/// monitored items, including this one.
+///
+/// # Backpressure
+///
+/// Notifications are forwarded through a bounded channel (see [`NOTIFICATION_BUFFER_SIZE`]).
+/// Once the channel is full, the oldest buffered notification is dropped to make room for the
+/// newest one: a consumer that falls behind loses intermediate values but keeps seeing up-to-date
+/// ones, rather than blocking the background task or growing memory without bound.
+pub struct MonitoredItemStream(AsyncMonitoredItem);
+
+impl MonitoredItemStream {
+    pub(crate) const fn new(item: AsyncMonitoredItem) -> Self {
+        Self(item)
+    }
+
+    /// Returns the monitored item ID assigned by the server.
+    #[must_use]
+    pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.0.monitored_item_id()
+    }
+}
+
+impl Stream for MonitoredItemStream {
+    type Item = ua::DataValue;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().0).poll_next(cx)
+    }
+}
+
+impl Drop for AsyncMonitoredItem {
+    fn drop(&mut self) {
+        if let Some(registry) = self.registry.as_ref().and_then(Weak::upgrade) {
+            if let Ok(mut items) = registry.lock() {
+                let _unused = items.remove(&self.monitored_item_id);
+            }
+        }
+
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+
+        delete_monitored_item(&client, &self.subscription_id, &self.monitored_item_id);
+    }
+}
+
+/// Creates several monitored items with a single service call.
+///
+/// The result vector has the same length and order as `specs`; each element reports the outcome
+/// for the corresponding specification.
+pub(crate) async fn create_monitored_items_batch(
+    client: &Arc<Mutex<ua::Client>>,
+    subscription_id: &ua::SubscriptionId,
+    specs: &[MonitoredItemSpec],
+    registry: &MonitoredItemRegistry,
+) -> Vec<Result<AsyncMonitoredItem, Error>> {
+    if specs.is_empty() {
+        return Vec::new();
+    }
+
+    let items_to_create: Vec<_> = specs
+        .iter()
+        .map(|spec| spec.parameters.as_request(&spec.node_id))
+        .collect();
+
+    // Each item needs its own data-change context and channel, so that notifications for
+    // different nodes don't get mixed up; the contexts (and the `Box` allocations backing the raw
+    // pointers handed to the FFI call below) must outlive that call.
+    let mut contexts: Vec<Box<DataChangeContext>> = Vec::with_capacity(specs.len());
+    let mut receivers = Vec::with_capacity(specs.len());
+    for _ in specs {
+        let (sender, receiver) = mpsc::channel(NOTIFICATION_BUFFER_SIZE);
+        contexts.push(Box::new(DataChangeContext { sender }));
+        receivers.push(receiver);
+    }
+    let context_ptrs: Vec<*mut c_void> = contexts
+        .iter_mut()
+        .map(|context| ptr::from_mut(context.as_mut()).cast::<c_void>())
+        .collect();
+
+    let outcomes = match create_monitored_items(
+        client,
+        subscription_id,
+        &items_to_create,
+        &context_ptrs,
+    )
+    .await
+    {
+        Ok(outcomes) => outcomes,
+        Err(error) => {
+            log::warn!("Batch MonitoredItems_createDataChanges() failed: {error}");
+            return specs
+                .iter()
+                .map(|_| Err(Error::internal("batch monitored item creation failed")))
+                .collect();
+        }
+    };
+
+    // The OPC UA specification states that the resulting list has the same number of elements as
+    // the request list. If not, we would not be able to match elements in the two lists anyway.
+    debug_assert_eq!(outcomes.len(), specs.len());
+
+    outcomes
+        .into_iter()
+        .zip(contexts)
+        .zip(receivers)
+        .map(|((outcome, context), receiver)| {
+            let monitored_item_id = outcome.map_err(Error::new)?;
+
+            if let Ok(mut items) = registry.lock() {
+                let is_duplicate = items.insert(monitored_item_id, ()).is_some();
+                if is_duplicate {
+                    log::warn!(
+                        "Server returned already-registered monitored item ID {monitored_item_id:?}"
+                    );
+                }
+            }
+
+            Ok(AsyncMonitoredItem {
+                client: Arc::downgrade(client),
+                subscription_id: *subscription_id,
+                monitored_item_id,
+                registry: Some(Arc::downgrade(registry)),
+                receiver,
+                _context: context,
+            })
+        })
+        .collect()
+}
+
+/// Deletes several monitored items with a single `MonitoredItems_delete` service call.
+pub(crate) fn delete_monitored_items_batch(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    monitored_item_ids: &[ua::MonitoredItemId],
+) {
+    delete_monitored_items(client, subscription_id, monitored_item_ids);
+}
+
+async fn create_monitored_item(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    item_to_create: ua::MonitoredItemCreateRequest,
+    mon_context: *mut c_void,
+) -> Result<ua::MonitoredItemId, Error> {
+    type Cb = CallbackOnce<Result<ua::MonitoredItemId, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("MonitoredItems_createDataChange() completed");
+
+        let response = response.cast::<UA_CreateMonitoredItemsResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            // PANIC: We expect the server to return exactly one result for our single request.
+            let results = unsafe { slice_from_raw(response.results, response.resultsSize) };
+            let result = results.first().expect("response should contain a result");
+            let result_status = ua::StatusCode::new(result.statusCode);
+
+            if result_status.is_good() {
+                Ok(ua::MonitoredItemId::new(result.monitoredItemId))
+            } else {
+                Err(result_status)
+            }
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<ua::MonitoredItemId, Error>>();
+
+    let callback = |result: Result<ua::MonitoredItemId, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been canceled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    let status_code = ua::StatusCode::new({
+        let Ok(mut client) = client.lock() else {
+            return Err(Error::internal("should be able to lock client"));
+        };
+
+        log::debug!("Calling MonitoredItems_createDataChange()");
+
+        // SAFETY: The request is converted to its raw representation right before the call, which
+        // takes it by value and does not take ownership; `item_to_create` (the owned request)
+        // stays alive until this block ends, keeping the raw struct's borrowed pointers valid.
+        let item_to_create = unsafe { ua::MonitoredItemCreateRequest::to_raw_copy(&item_to_create) };
+
+        unsafe {
+            UA_Client_MonitoredItems_createDataChange_async(
+                client.as_mut_ptr(),
+                subscription_id.as_raw(),
+                open62541_sys::UA_TimestampsToReturn::UA_TIMESTAMPSTORETURN_BOTH,
+                item_to_create,
+                mon_context,
+                Some(data_change_callback_c),
+                None,
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    rx.await
+        .unwrap_or(Err(Error::internal("callback should send result")))
+}
+
+/// Creates several monitored items with a single `MonitoredItems_createDataChanges` service call.
+///
+/// The result vector has the same length and order as `items_to_create` and `contexts`; each
+/// element reports the server's per-item outcome. `contexts[i]` is handed to the data-change
+/// callback as the `mon_context` for `items_to_create[i]`'s monitored item once created, exactly
+/// like the single-item [`create_monitored_item()`] above.
+async fn create_monitored_items(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    items_to_create: &[ua::MonitoredItemCreateRequest],
+    contexts: &[*mut c_void],
+) -> Result<Vec<Result<ua::MonitoredItemId, ua::StatusCode>>, Error> {
+    type Cb = CallbackOnce<Result<Vec<Result<ua::MonitoredItemId, ua::StatusCode>>, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("MonitoredItems_createDataChanges() completed");
+
+        let response = response.cast::<UA_CreateMonitoredItemsResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            let results = unsafe { slice_from_raw(response.results, response.resultsSize) };
+            Ok(results
+                .iter()
+                .map(|result| {
+                    let result_status = ua::StatusCode::new(result.statusCode);
+                    if result_status.is_good() {
+                        Ok(ua::MonitoredItemId::new(result.monitoredItemId))
+                    } else {
+                        Err(result_status)
+                    }
+                })
+                .collect())
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) =
+        oneshot::channel::<Result<Vec<Result<ua::MonitoredItemId, ua::StatusCode>>, Error>>();
+
+    let callback = |result: Result<Vec<Result<ua::MonitoredItemId, ua::StatusCode>>, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been canceled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    let request = ua::CreateMonitoredItemsRequest::init()
+        .with_subscription_id(*subscription_id)
+        .with_timestamps_to_return(open62541_sys::UA_TimestampsToReturn::UA_TIMESTAMPSTORETURN_BOTH)
+        .with_items_to_create(items_to_create);
+
+    // One data-change callback per item, all pointing at the same trampoline: which channel each
+    // notification ends up in is decided by `mon_context` (the matching entry of `contexts`), not
+    // by which function pointer fired. No per-item delete callback is used here either, mirroring
+    // the single-item call above.
+    let callbacks = vec![Some(data_change_callback_c); items_to_create.len()];
+    let delete_callbacks: Vec<
+        Option<unsafe extern "C" fn(*mut UA_Client, UA_UInt32, *mut c_void, UA_UInt32, *mut c_void)>,
+    > = vec![None; items_to_create.len()];
+
+    let status_code = ua::StatusCode::new({
+        let Ok(mut client) = client.lock() else {
+            return Err(Error::internal("should be able to lock client"));
+        };
+
+        log::debug!(
+            "Calling MonitoredItems_createDataChanges() for {} item(s)",
+            items_to_create.len()
+        );
+
+        // SAFETY: The request is converted to its raw representation right before the call, which
+        // takes it by value and does not take ownership; `request` (the owned request) stays alive
+        // until this block ends, keeping the raw struct's borrowed pointers (the items to create)
+        // valid.
+        let request = unsafe { ua::CreateMonitoredItemsRequest::to_raw_copy(&request) };
+
+        unsafe {
+            UA_Client_MonitoredItems_createDataChanges_async(
+                client.as_mut_ptr(),
+                request,
+                contexts.as_ptr().cast_mut(),
+                callbacks.as_ptr().cast_mut(),
+                delete_callbacks.as_ptr().cast_mut(),
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    rx.await
+        .unwrap_or(Err(Error::internal("callback should send result")))
+}
+
+pub(crate) fn delete_monitored_item(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    monitored_item_id: &ua::MonitoredItemId,
+) {
+    delete_monitored_items(client, subscription_id, std::slice::from_ref(monitored_item_id));
+}
+
+/// Deletes one or more monitored items with a single `MonitoredItems_delete` service call.
+fn delete_monitored_items(
+    client: &Mutex<ua::Client>,
+    subscription_id: &ua::SubscriptionId,
+    monitored_item_ids: &[ua::MonitoredItemId],
+) {
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        _userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        _response: *mut c_void,
+    ) {
+        log::debug!("MonitoredItems_delete() completed");
+
+        // Nothing to do here.
+    }
+
+    if monitored_item_ids.is_empty() {
+        return;
+    }
+
+    let _unused = {
+        let Ok(mut client) = client.lock() else {
+            return;
+        };
+
+        log::debug!("Calling MonitoredItems_delete() for {} item(s)", monitored_item_ids.len());
+
+        unsafe {
+            UA_Client_MonitoredItems_delete_async(
+                client.as_mut_ptr(),
+                subscription_id.as_raw(),
+                monitored_item_ids.as_ptr().cast::<UA_UInt32>().cast_mut(),
+                monitored_item_ids.len() as UA_UInt32,
+                // This must be set (despite the `Option` type), mirroring the callback in
+                // `delete_subscription()` in `async_subscription`.
+                Some(callback_c),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        }
+    };
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for `len` elements, or `len` must be `0`.
+pub(crate) unsafe fn slice_from_raw<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    }
+}
@@ -1,35 +1,374 @@
 use std::{
+    collections::{HashMap, HashSet},
     ffi::c_void,
+    pin::Pin,
     ptr,
     sync::{Arc, Mutex, Weak},
+    task::{Context as TaskContext, Poll},
+    time::Duration,
 };
 
 use futures_channel::oneshot;
+use futures_core::Stream;
 use open62541_sys::{
     UA_Client, UA_Client_Subscriptions_create_async, UA_Client_Subscriptions_delete_async,
-    UA_CreateSubscriptionResponse, UA_UInt32,
+    UA_Client_Subscriptions_republish_async, UA_CreateSubscriptionResponse,
+    UA_RepublishResponse, UA_STATUSCODE_BADMESSAGENOTAVAILABLE, UA_UInt32,
 };
+use tokio::sync::mpsc;
 
-use crate::{ua, AsyncMonitoredItem, CallbackOnce, DataType as _, Error};
+use crate::{
+    async_event::EventStream,
+    async_monitored_item::{create_monitored_items_batch, delete_monitored_items_batch},
+    ua, AsyncMonitoredItem, CallbackOnce, DataType as _, Error, EventFilter, MonitoredItemSpec,
+    MonitoredItemStream, MonitoringParameters,
+};
+
+/// Subscription-level event delivered out-of-band from data-change notifications.
+///
+/// Obtain a stream of these via [`AsyncSubscription::status_changes()`].
+#[derive(Debug, Clone)]
+pub enum SubscriptionStatus {
+    /// The server sent a keep-alive: nothing changed, but the subscription is still alive.
+    KeepAlive,
+    /// The subscription status changed on the server, e.g. it is about to expire.
+    StatusChanged(ua::StatusCode),
+    /// The subscription was deleted on the server (e.g. due to inactivity).
+    Deleted,
+    /// Notifications missed while disconnected were recovered via `Republish` after a reconnect.
+    ///
+    /// The background task transparently requests these for every sequence number the server
+    /// reports as still available in the `TransferSubscriptions` response (see
+    /// [`AsyncClient::create_subscription()`](crate::AsyncClient::create_subscription)'s reconnect
+    /// handling). Sequence numbers the server has already discarded are not included here; use
+    /// [`republish_missing()`](AsyncSubscription::republish_missing) to attempt recovery of a
+    /// specific range yourself if your application tracks sequence numbers from live
+    /// notifications.
+    Recovered(Vec<ua::NotificationMessage>),
+}
+
+/// Context passed to the FFI status-change/delete callbacks for the lifetime of a subscription.
+struct StatusContext {
+    sender: mpsc::Sender<SubscriptionStatus>,
+}
+
+unsafe extern "C" fn status_change_callback_c(
+    _client: *mut UA_Client,
+    _sub_id: UA_UInt32,
+    sub_context: *mut c_void,
+    notification: *mut open62541_sys::UA_StatusChangeNotification,
+) {
+    log::debug!("Subscription status change notification received");
+
+    // SAFETY: `sub_context` is the pointer we passed in when creating the subscription, and it
+    // stays valid for as long as the subscription exists.
+    let context = unsafe { sub_context.cast::<StatusContext>().as_ref() }
+        .expect("subscription context should be set");
+
+    let status = if let Some(notification) = unsafe { notification.as_ref() } {
+        SubscriptionStatus::StatusChanged(ua::StatusCode::new(notification.status))
+    } else {
+        SubscriptionStatus::KeepAlive
+    };
+
+    let _unused = context.sender.try_send(status);
+}
+
+unsafe extern "C" fn subscription_delete_callback_c(_client: *mut UA_Client, sub_context: *mut c_void) {
+    log::debug!("Subscription delete notification received");
+
+    // SAFETY: `sub_context` is the pointer we passed in when creating the subscription, and it
+    // stays valid until this very callback has run (deletion is the last thing to happen to it).
+    let context = unsafe { sub_context.cast::<StatusContext>().as_ref() }
+        .expect("subscription context should be set");
+
+    let _unused = context.sender.try_send(SubscriptionStatus::Deleted);
+}
+
+/// Requested parameters for creating a [subscription](AsyncSubscription).
+///
+/// Use this to tune the OPC UA subscription to the application's needs, e.g. slow polling for
+/// low-priority data, or high priority and short keep-alive intervals for alarms. The server may
+/// revise any of these values; the actual values in use are available via
+/// [`AsyncSubscription::revised_publishing_interval()`] and friends once the subscription has been
+/// created.
+///
+/// The default value of each parameter matches the `open62541` library default (i.e. what
+/// [`AsyncSubscription::new()`] uses implicitly).
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionOptions {
+    requested_publishing_interval: Option<Duration>,
+    requested_lifetime_count: Option<u32>,
+    requested_max_keep_alive_count: Option<u32>,
+    max_notifications_per_publish: Option<u32>,
+    priority: Option<u8>,
+}
+
+impl SubscriptionOptions {
+    /// Sets requested publishing interval.
+    #[must_use]
+    pub fn with_publishing_interval(mut self, publishing_interval: Duration) -> Self {
+        self.requested_publishing_interval = Some(publishing_interval);
+        self
+    }
+
+    /// Sets requested lifetime count.
+    ///
+    /// This is the number of publishing intervals the subscription is allowed to miss before it
+    /// is deleted by the server (due to lack of acknowledged publish requests).
+    #[must_use]
+    pub const fn with_lifetime_count(mut self, lifetime_count: u32) -> Self {
+        self.requested_lifetime_count = Some(lifetime_count);
+        self
+    }
+
+    /// Sets requested maximum keep-alive count.
+    ///
+    /// This is the number of publishing intervals without notifications after which the server
+    /// sends a keep-alive message.
+    #[must_use]
+    pub const fn with_max_keep_alive_count(mut self, max_keep_alive_count: u32) -> Self {
+        self.requested_max_keep_alive_count = Some(max_keep_alive_count);
+        self
+    }
+
+    /// Sets maximum number of notifications per publish response.
+    ///
+    /// Use `0` to let the server decide (this is also the default).
+    #[must_use]
+    pub const fn with_max_notifications_per_publish(mut self, max_notifications: u32) -> Self {
+        self.max_notifications_per_publish = Some(max_notifications);
+        self
+    }
+
+    /// Sets subscription priority.
+    ///
+    /// Higher values indicate higher priority, e.g. for alarm and event subscriptions that should
+    /// be serviced ahead of regular data subscriptions when the server is under load.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    fn as_request(&self) -> ua::CreateSubscriptionRequest {
+        let mut request = ua::CreateSubscriptionRequest::default();
+
+        if let Some(publishing_interval) = self.requested_publishing_interval {
+            request = request
+                .with_requested_publishing_interval(publishing_interval.as_secs_f64() * 1000.0);
+        }
+        if let Some(lifetime_count) = self.requested_lifetime_count {
+            request = request.with_requested_lifetime_count(lifetime_count);
+        }
+        if let Some(max_keep_alive_count) = self.requested_max_keep_alive_count {
+            request = request.with_requested_max_keep_alive_count(max_keep_alive_count);
+        }
+        if let Some(max_notifications) = self.max_notifications_per_publish {
+            request = request.with_max_notifications_per_publish(max_notifications);
+        }
+        if let Some(priority) = self.priority {
+            request = request.with_priority(priority);
+        }
+
+        request
+    }
+}
+
+/// Size of the buffer used to forward subscription status notifications to consumers.
+const STATUS_BUFFER_SIZE: usize = 16;
 
 /// Subscription (with asynchronous API).
 pub struct AsyncSubscription {
     client: Weak<Mutex<ua::Client>>,
     subscription_id: ua::SubscriptionId,
+    revised_publishing_interval: Duration,
+    revised_lifetime_count: u32,
+    revised_max_keep_alive_count: u32,
+    // Tracks monitored items created through this subscription so that their creation can detect
+    // the server returning an ID that is already registered. On `Drop`, the items still alive are
+    // torn down on the server by deleting the subscription itself.
+    monitored_items: Arc<Mutex<HashMap<ua::MonitoredItemId, ()>>>,
+    status_receiver: mpsc::Receiver<SubscriptionStatus>,
+    // Kept alive for as long as the subscription exists: the status-change and delete callbacks
+    // hold a raw pointer into this allocation.
+    _status_context: Box<StatusContext>,
+    // Clone of the sender backing `status_receiver`, handed to the owner registry below so the
+    // reconnect supervisor can deliver `SubscriptionStatus::Recovered` after a transfer.
+    status_sender: mpsc::Sender<SubscriptionStatus>,
+    // Set by `AsyncClient::create_subscription()` so that the reconnect supervisor knows which
+    // subscriptions to restore (via `TransferSubscriptions`) after a reconnect, and where to
+    // deliver any notifications recovered along the way.
+    owner_registry: Option<Weak<Mutex<HashMap<ua::SubscriptionId, mpsc::Sender<SubscriptionStatus>>>>>,
 }
 
 impl AsyncSubscription {
     pub(crate) async fn new(client: &Arc<Mutex<ua::Client>>) -> Result<Self, Error> {
-        let request = ua::CreateSubscriptionRequest::default();
+        Self::new_with_config(client, &SubscriptionOptions::default()).await
+    }
+
+    /// Creates subscription with custom parameters.
+    ///
+    /// Use [`SubscriptionOptions`] to request a non-default publishing interval, lifetime count,
+    /// keep-alive count, maximum notifications per publish, or priority. The server may revise any
+    /// of these; inspect [`revised_publishing_interval()`](Self::revised_publishing_interval) and
+    /// friends to see the values that are actually in effect.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    pub(crate) async fn new_with_config(
+        client: &Arc<Mutex<ua::Client>>,
+        options: &SubscriptionOptions,
+    ) -> Result<Self, Error> {
+        let request = options.as_request();
 
-        let response = create_subscription(client, &request).await?;
+        let (sender, status_receiver) = mpsc::channel(STATUS_BUFFER_SIZE);
+        let status_sender = sender.clone();
+        let mut status_context = Box::new(StatusContext { sender });
+
+        let response = create_subscription(
+            client,
+            &request,
+            ptr::from_mut(status_context.as_mut()).cast::<c_void>(),
+        )
+        .await?;
 
         Ok(AsyncSubscription {
             client: Arc::downgrade(client),
             subscription_id: response.subscription_id(),
+            revised_publishing_interval: Duration::from_secs_f64(
+                response.revised_publishing_interval() / 1000.0,
+            ),
+            revised_lifetime_count: response.revised_lifetime_count(),
+            revised_max_keep_alive_count: response.revised_max_keep_alive_count(),
+            monitored_items: Arc::new(Mutex::new(HashMap::new())),
+            status_receiver,
+            _status_context: status_context,
+            status_sender,
+            owner_registry: None,
         })
     }
 
+    /// Returns the subscription ID assigned by the server.
+    #[must_use]
+    pub const fn subscription_id(&self) -> ua::SubscriptionId {
+        self.subscription_id
+    }
+
+    /// Registers this subscription with the owning [`AsyncClient`](crate::AsyncClient)'s
+    /// reconnect supervisor, so it is included in `TransferSubscriptions` requests after a
+    /// reconnect, and so notifications recovered along the way can be delivered as
+    /// [`SubscriptionStatus::Recovered`].
+    pub(crate) fn attach_owner_registry(
+        &mut self,
+        registry: &Arc<Mutex<HashMap<ua::SubscriptionId, mpsc::Sender<SubscriptionStatus>>>>,
+    ) {
+        if let Ok(mut subscriptions) = registry.lock() {
+            subscriptions.insert(self.subscription_id, self.status_sender.clone());
+        }
+        self.owner_registry = Some(Arc::downgrade(registry));
+    }
+
+    /// Returns a [`Stream`] of subscription-level status notifications.
+    ///
+    /// This yields [`SubscriptionStatus::KeepAlive`] whenever the server sends a publish response
+    /// with no notifications (confirming the subscription is still alive),
+    /// [`SubscriptionStatus::StatusChanged`] when the subscription's status changes, and
+    /// [`SubscriptionStatus::Deleted`] once the server has deleted the subscription (e.g. after it
+    /// timed out due to a lack of publish requests).
+    pub fn status_changes(&mut self) -> impl Stream<Item = SubscriptionStatus> + '_ {
+        StatusStream {
+            receiver: &mut self.status_receiver,
+        }
+    }
+
+    /// Manually recovers notifications for sequence numbers you already know were missed.
+    ///
+    /// This issues a `Republish` request for each of the given sequence numbers, in order. It stops
+    /// as soon as the server reports a sequence number as no longer available (`BadMessageNotAvailable`,
+    /// which is not treated as an error: the corresponding and all following sequence numbers are
+    /// simply missing from the result), and it never requests (or implicitly acknowledges) the same
+    /// sequence number twice within one call, even if it appears more than once in
+    /// `sequence_numbers`.
+    ///
+    /// # Limitations
+    ///
+    /// This crate does not currently expose sequence numbers for *live* data-change notifications:
+    /// they arrive as bare [`ua::DataValue`]s via [`AsyncMonitoredItem`](crate::AsyncMonitoredItem),
+    /// with no accompanying sequence number. So detecting a gap in delivered notifications, and
+    /// calling this method with the missing range, is **not possible through this crate's public
+    /// API today** &mdash; despite that being the usual way `Republish` is driven in the OPC UA
+    /// specification. There is also no protection against a sequence number recovered here arriving
+    /// again via a late publish response that was already in flight when you called this: dedup
+    /// against *live* notifications is the caller's responsibility (this method only dedups within
+    /// the `sequence_numbers` passed to a single call).
+    ///
+    /// The one case this crate does handle automatically is reconnecting: the background task
+    /// already republishes everything the server reports as available in the
+    /// `TransferSubscriptions` response and delivers it via [`SubscriptionStatus::Recovered`]. Call
+    /// this method directly only if you have your own source of missed sequence numbers (e.g.
+    /// tracked externally to this crate).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected or a `Republish` request cannot be sent.
+    pub async fn republish_missing(
+        &self,
+        sequence_numbers: impl IntoIterator<Item = u32>,
+    ) -> Result<Vec<ua::NotificationMessage>, Error> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let mut recovered = Vec::new();
+        let mut requested = HashSet::new();
+
+        for sequence_number in sequence_numbers {
+            if !requested.insert(sequence_number) {
+                // Already recovered above; skip it to avoid acknowledging the same sequence number
+                // more than once.
+                continue;
+            }
+
+            match republish(&client, self.subscription_id, sequence_number).await? {
+                Some(notification_message) => recovered.push(notification_message),
+                None => {
+                    log::debug!(
+                        "Stopping republish: sequence number {sequence_number} no longer available"
+                    );
+                    break;
+                }
+            }
+        }
+
+        Ok(recovered)
+    }
+
+    /// Returns the publishing interval revised by the server.
+    ///
+    /// This may differ from the interval that was requested via [`SubscriptionOptions`].
+    #[must_use]
+    pub const fn revised_publishing_interval(&self) -> Duration {
+        self.revised_publishing_interval
+    }
+
+    /// Returns the lifetime count revised by the server.
+    ///
+    /// This may differ from the count that was requested via [`SubscriptionOptions`].
+    #[must_use]
+    pub const fn revised_lifetime_count(&self) -> u32 {
+        self.revised_lifetime_count
+    }
+
+    /// Returns the maximum keep-alive count revised by the server.
+    ///
+    /// This may differ from the count that was requested via [`SubscriptionOptions`].
+    #[must_use]
+    pub const fn revised_max_keep_alive_count(&self) -> u32 {
+        self.revised_max_keep_alive_count
+    }
+
     /// Creates [monitored item](AsyncMonitoredItem).
     ///
     /// This creates a new monitored item for the given node.
@@ -41,11 +380,171 @@ impl AsyncSubscription {
         &self,
         node_id: &ua::NodeId,
     ) -> Result<AsyncMonitoredItem, Error> {
+        self.create_monitored_item_with_config(node_id, &MonitoringParameters::default())
+            .await
+    }
+
+    /// Creates [monitored item](AsyncMonitoredItem) with custom monitoring parameters.
+    ///
+    /// Use [`MonitoringParameters`] to pick the monitored attribute, sampling interval, queue
+    /// size, discard policy, monitoring mode, or a [`DataChangeFilter`](crate::DataChangeFilter)
+    /// to suppress uninteresting notifications (e.g. via a deadband).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or the given attribute cannot be monitored.
+    pub async fn create_monitored_item_with_config(
+        &self,
+        node_id: &ua::NodeId,
+        parameters: &MonitoringParameters,
+    ) -> Result<AsyncMonitoredItem, Error> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        AsyncMonitoredItem::new_registered(
+            &client,
+            &self.subscription_id,
+            node_id,
+            parameters,
+            Some(&self.monitored_items),
+        )
+        .await
+    }
+
+    /// Creates several [monitored items](AsyncMonitoredItem) with a single service call.
+    ///
+    /// This issues only one `CreateMonitoredItems` request to the OPC UA server (and should be
+    /// preferred over several individual calls to [`create_monitored_item()`] when monitoring
+    /// multiple nodes at once).
+    ///
+    /// The result vector has the same length and order as `specs`; each element reports the
+    /// outcome for the corresponding [`MonitoredItemSpec`].
+    ///
+    /// [`create_monitored_item()`]: Self::create_monitored_item
+    pub async fn create_monitored_items(
+        &self,
+        specs: &[MonitoredItemSpec],
+    ) -> Result<Vec<Result<AsyncMonitoredItem, Error>>, Error> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        Ok(create_monitored_items_batch(
+            &client,
+            &self.subscription_id,
+            specs,
+            &self.monitored_items,
+        )
+        .await)
+    }
+
+    /// Monitors a node attribute and returns a [`Stream`] of its data-change notifications.
+    ///
+    /// This is a convenience wrapper around [`create_monitored_item()`](Self::create_monitored_item)
+    /// for the common case of consuming notifications directly as a stream instead of holding on
+    /// to the [`AsyncMonitoredItem`] handle. Use [`monitor_with_config()`](Self::monitor_with_config)
+    /// to customize the sampling interval, queue size, or data-change filter.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or the given attribute cannot be monitored.
+    pub async fn monitor(
+        &self,
+        node_id: &ua::NodeId,
+        attribute_id: ua::AttributeId,
+    ) -> Result<MonitoredItemStream, Error> {
+        self.monitor_with_config(
+            node_id,
+            &MonitoringParameters::default().with_attribute_id(attribute_id),
+        )
+        .await
+    }
+
+    /// Monitors a node attribute with custom [`MonitoringParameters`] and returns a [`Stream`] of
+    /// its data-change notifications.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or the given attribute cannot be monitored.
+    pub async fn monitor_with_config(
+        &self,
+        node_id: &ua::NodeId,
+        parameters: &MonitoringParameters,
+    ) -> Result<MonitoredItemStream, Error> {
+        let item = self
+            .create_monitored_item_with_config(node_id, parameters)
+            .await?;
+        Ok(MonitoredItemStream::new(item))
+    }
+
+    /// Monitors a node for events matching `filter` and returns a [`Stream`] of their field
+    /// values.
+    ///
+    /// This is used for OPC UA events (e.g. alarms and conditions) rather than data changes: the
+    /// node is usually an object (such as the `Server` object) with an `EventNotifier` attribute,
+    /// not a variable. Use [`EventFilter::with_select_clause()`] to pick which fields of matching
+    /// events are delivered, and [`EventFilter::with_where_clause()`] to restrict which events are
+    /// reported at all.
+    ///
+    /// Each item of the returned stream is one row of field values, in the same order as the
+    /// filter's select clauses.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or does not support events.
+    pub async fn monitor_events(
+        &self,
+        node_id: &ua::NodeId,
+        filter: &EventFilter,
+    ) -> Result<EventStream, Error> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        EventStream::new(&client, &self.subscription_id, node_id, filter).await
+    }
+
+    /// Deletes several monitored items with a single service call.
+    ///
+    /// This is mainly useful to delete monitored items by ID without holding on to their
+    /// [`AsyncMonitoredItem`] handles (which would otherwise delete themselves individually when
+    /// dropped).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    pub fn delete_monitored_items(
+        &self,
+        monitored_item_ids: &[ua::MonitoredItemId],
+    ) -> Result<(), Error> {
         let Some(client) = self.client.upgrade() else {
             return Err(Error::internal("client should not be dropped"));
         };
 
-        AsyncMonitoredItem::new(&client, &self.subscription_id, node_id).await
+        if let Ok(mut items) = self.monitored_items.lock() {
+            for monitored_item_id in monitored_item_ids {
+                let _unused = items.remove(monitored_item_id);
+            }
+        }
+
+        delete_monitored_items_batch(&client, &self.subscription_id, monitored_item_ids);
+
+        Ok(())
+    }
+}
+
+/// Borrowing [`Stream`] adapter over a [`mpsc::Receiver`], returned by
+/// [`AsyncSubscription::status_changes()`].
+struct StatusStream<'a> {
+    receiver: &'a mut mpsc::Receiver<SubscriptionStatus>,
+}
+
+impl Stream for StatusStream<'_> {
+    type Item = SubscriptionStatus;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
     }
 }
 
@@ -55,6 +554,20 @@ impl Drop for AsyncSubscription {
             return;
         };
 
+        // Deleting the subscription implicitly deletes all of its monitored items on the server,
+        // so no separate delete call is issued for them here. Clearing the registry does not stop
+        // `AsyncMonitoredItem` handles the caller still holds from issuing their own delete
+        // request once dropped, for a subscription that by then no longer exists.
+        if let Ok(mut items) = self.monitored_items.lock() {
+            items.clear();
+        }
+
+        if let Some(registry) = self.owner_registry.as_ref().and_then(Weak::upgrade) {
+            if let Ok(mut subscriptions) = registry.lock() {
+                let _unused = subscriptions.remove(&self.subscription_id);
+            }
+        }
+
         let request =
             ua::DeleteSubscriptionsRequest::init().with_subscription_ids(&[self.subscription_id]);
 
@@ -65,6 +578,7 @@ impl Drop for AsyncSubscription {
 async fn create_subscription(
     client: &Mutex<ua::Client>,
     request: &ua::CreateSubscriptionRequest,
+    status_context: *mut c_void,
 ) -> Result<ua::CreateSubscriptionResponse, Error> {
     type Cb = CallbackOnce<Result<ua::CreateSubscriptionResponse, ua::StatusCode>>;
 
@@ -118,9 +632,9 @@ async fn create_subscription(
             UA_Client_Subscriptions_create_async(
                 client.as_mut_ptr(),
                 request,
-                ptr::null_mut(),
-                None,
-                None,
+                status_context,
+                Some(status_change_callback_c),
+                Some(subscription_delete_callback_c),
                 Some(callback_c),
                 Cb::prepare(callback),
                 ptr::null_mut(),
@@ -173,3 +687,120 @@ fn delete_subscription(client: &Mutex<ua::Client>, request: &ua::DeleteSubscript
         }
     };
 }
+
+/// Recovers notifications after a reconnect, via `Republish`, for each sequence number that
+/// `TransferSubscriptions` reported as still available for `subscription_id`.
+///
+/// Unlike [`AsyncSubscription::republish_missing()`], this does not stop at the first sequence
+/// number the server reports as no longer available: every entry in `available_sequence_numbers`
+/// came straight from the server's own `TransferResult`, so each is attempted independently and a
+/// failure (or a since-expired sequence number) only affects that one notification, which is
+/// logged and otherwise ignored.
+pub(crate) async fn recover_missing_after_transfer(
+    client: &Mutex<ua::Client>,
+    subscription_id: ua::SubscriptionId,
+    available_sequence_numbers: &[u32],
+) -> Vec<ua::NotificationMessage> {
+    let mut recovered = Vec::with_capacity(available_sequence_numbers.len());
+
+    for &sequence_number in available_sequence_numbers {
+        match republish(client, subscription_id, sequence_number).await {
+            Ok(Some(notification_message)) => recovered.push(notification_message),
+            Ok(None) => {
+                log::debug!(
+                    "Sequence number {sequence_number} for subscription {subscription_id:?} was \
+                     no longer available while recovering after reconnect"
+                );
+            }
+            Err(error) => {
+                log::warn!(
+                    "Failed to recover sequence number {sequence_number} for subscription \
+                     {subscription_id:?} after reconnect: {error}"
+                );
+            }
+        }
+    }
+
+    recovered
+}
+
+/// Issues a single `Republish` request.
+///
+/// Returns `Ok(None)` (not an error) when the server reports `BadMessageNotAvailable`, i.e. the
+/// requested sequence number is no longer available for retransmission.
+async fn republish(
+    client: &Mutex<ua::Client>,
+    subscription_id: ua::SubscriptionId,
+    retransmit_sequence_number: u32,
+) -> Result<Option<ua::NotificationMessage>, Error> {
+    type Cb = CallbackOnce<Result<ua::NotificationMessage, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("Subscriptions_republish() completed");
+
+        let response = response.cast::<UA_RepublishResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            Ok(ua::NotificationMessage::clone_raw(&response.notificationMessage))
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<ua::NotificationMessage, ua::StatusCode>>();
+
+    let callback = |result: Result<ua::NotificationMessage, ua::StatusCode>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been canceled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result);
+    };
+
+    let status_code = ua::StatusCode::new({
+        let Ok(mut client) = client.lock() else {
+            return Err(Error::internal("should be able to lock client"));
+        };
+
+        log::debug!("Calling Subscriptions_republish()");
+
+        unsafe {
+            UA_Client_Subscriptions_republish_async(
+                client.as_mut_ptr(),
+                subscription_id.as_raw(),
+                retransmit_sequence_number,
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    match rx
+        .await
+        .unwrap_or(Err(ua::StatusCode::new(open62541_sys::UA_STATUSCODE_BADINTERNALERROR)))
+    {
+        Ok(notification_message) => Ok(Some(notification_message)),
+        Err(status_code) if status_code.into_raw() == UA_STATUSCODE_BADMESSAGENOTAVAILABLE => {
+            Ok(None)
+        }
+        Err(status_code) => Err(Error::new(status_code)),
+    }
+}
@@ -0,0 +1,364 @@
+use std::{ffi::CString, time::Duration};
+
+use open62541_sys::{
+    UA_Client_connect, UA_Client_new, UA_ClientConfig_setAuthenticationUsername,
+    UA_ClientConfig_setDefault, UA_ClientConfig_setDefaultEncryption,
+};
+
+use crate::{ua, AsyncClient, DataType as _, Error};
+
+/// OPC UA message security mode, controlling whether messages on a secure channel are signed
+/// and/or encrypted.
+///
+/// Use with [`ClientBuilder::with_security()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageSecurityMode {
+    /// No security is applied to messages. This is the default when no security is configured.
+    #[default]
+    None,
+    /// Messages are signed but not encrypted.
+    Sign,
+    /// Messages are signed and encrypted.
+    SignAndEncrypt,
+}
+
+impl MessageSecurityMode {
+    const fn as_raw(self) -> open62541_sys::UA_MessageSecurityMode {
+        match self {
+            Self::None => open62541_sys::UA_MessageSecurityMode::UA_MESSAGESECURITYMODE_NONE,
+            Self::Sign => open62541_sys::UA_MessageSecurityMode::UA_MESSAGESECURITYMODE_SIGN,
+            Self::SignAndEncrypt => {
+                open62541_sys::UA_MessageSecurityMode::UA_MESSAGESECURITYMODE_SIGNANDENCRYPT
+            }
+        }
+    }
+}
+
+/// OPC UA security policy, identifying the cryptographic algorithms used to secure a channel.
+///
+/// Use with [`ClientBuilder::with_security()`]. Note that `open62541` always registers all of
+/// these policies once encryption is configured; selecting one here only sets the endpoint's
+/// preferred `securityPolicyUri` used to pick a matching endpoint, it does not disable the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityPolicy {
+    /// `Basic256Sha256` security policy.
+    Basic256Sha256,
+    /// `Aes128_Sha256_RsaOaep` security policy.
+    Aes128Sha256RsaOaep,
+    /// `Aes256_Sha256_RsaPss` security policy.
+    Aes256Sha256RsaPss,
+}
+
+impl SecurityPolicy {
+    const fn uri(self) -> &'static str {
+        match self {
+            Self::Basic256Sha256 => "http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256",
+            Self::Aes128Sha256RsaOaep => {
+                "http://opcfoundation.org/UA/SecurityPolicy#Aes128_Sha256_RsaOaep"
+            }
+            Self::Aes256Sha256RsaPss => {
+                "http://opcfoundation.org/UA/SecurityPolicy#Aes256_Sha256_RsaPss"
+            }
+        }
+    }
+}
+
+/// Client application certificate, private key, and trusted certificates used to secure the
+/// channel. Set via [`ClientBuilder::with_security()`] and [`ClientBuilder::with_trust_list()`].
+#[derive(Debug, Clone)]
+struct SecurityConfig {
+    mode: MessageSecurityMode,
+    policy: SecurityPolicy,
+    certificate: ua::ByteString,
+    private_key: ua::ByteString,
+    trust_list: Vec<ua::ByteString>,
+}
+
+/// User identity token presented to the server upon connecting.
+///
+/// Set via [`ClientBuilder::with_user_identity_username()`] or
+/// [`ClientBuilder::with_user_identity_certificate()`]. Defaults to anonymous.
+#[derive(Debug, Clone, Default)]
+enum IdentityToken {
+    #[default]
+    Anonymous,
+    UserName {
+        username: String,
+        password: String,
+    },
+    X509 {
+        certificate: ua::ByteString,
+    },
+}
+
+/// Default base delay between reconnection attempts.
+const DEFAULT_RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Default upper bound for the reconnection backoff delay.
+const DEFAULT_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Backoff parameters used by the reconnection supervisor.
+///
+/// See [`ClientBuilder::with_reconnect_backoff()`] and
+/// [`ClientBuilder::with_max_reconnect_attempts()`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReconnectConfig {
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: DEFAULT_RECONNECT_BASE_DELAY,
+            max_delay: DEFAULT_RECONNECT_MAX_DELAY,
+            // Retry forever by default: a client that gives up silently is worse than one that
+            // keeps trying, and callers who want bounded retries can opt in explicitly.
+            max_attempts: None,
+        }
+    }
+}
+
+/// Builder for connecting a [`Client`](crate::Client).
+///
+/// Use [`connect()`](Self::connect) to establish the connection, then
+/// [`into_async()`](crate::Client::into_async) to obtain the asynchronous API
+/// ([`AsyncClient`]).
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    pub(crate) reconnect: ReconnectConfig,
+    security: Option<SecurityConfig>,
+    identity: IdentityToken,
+}
+
+impl ClientBuilder {
+    /// Enables channel encryption with the given security mode and policy.
+    ///
+    /// `certificate` and `private_key` are the client application's own certificate and private
+    /// key, each as DER-encoded bytes (a PEM-encoded key is also accepted by `open62541`, which
+    /// auto-detects the encoding). Use [`with_trust_list()`](Self::with_trust_list) to add the
+    /// server (or CA) certificates this client should trust; without one, no server certificate is
+    /// accepted and the connection attempt fails during the security handshake.
+    #[must_use]
+    pub fn with_security(
+        mut self,
+        mode: MessageSecurityMode,
+        policy: SecurityPolicy,
+        certificate: &[u8],
+        private_key: &[u8],
+    ) -> Self {
+        self.security = Some(SecurityConfig {
+            mode,
+            policy,
+            certificate: ua::ByteString::from_bytes(certificate),
+            private_key: ua::ByteString::from_bytes(private_key),
+            trust_list: Vec::new(),
+        });
+        self
+    }
+
+    /// Sets the list of trusted certificates (DER-encoded), used to validate the server's
+    /// certificate during the security handshake.
+    ///
+    /// This has no effect unless [`with_security()`](Self::with_security) was also called.
+    #[must_use]
+    pub fn with_trust_list<'a>(mut self, trust_list: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        if let Some(security) = &mut self.security {
+            security.trust_list = trust_list
+                .into_iter()
+                .map(ua::ByteString::from_bytes)
+                .collect();
+        }
+        self
+    }
+
+    /// Authenticates with username and password instead of anonymously.
+    #[must_use]
+    pub fn with_user_identity_username(mut self, username: &str, password: &str) -> Self {
+        self.identity = IdentityToken::UserName {
+            username: username.to_owned(),
+            password: password.to_owned(),
+        };
+        self
+    }
+
+    /// Authenticates with an X.509 certificate (DER-encoded) instead of anonymously.
+    ///
+    /// Note that this identity certificate is separate from the channel-level client certificate
+    /// set via [`with_security()`](Self::with_security).
+    #[must_use]
+    pub fn with_user_identity_certificate(mut self, certificate: &[u8]) -> Self {
+        self.identity = IdentityToken::X509 {
+            certificate: ua::ByteString::from_bytes(certificate),
+        };
+        self
+    }
+
+    /// Sets the backoff delay range used when automatically reconnecting after a connection loss.
+    ///
+    /// The delay between attempts starts at `base_delay` and doubles after every failed attempt,
+    /// up to `max_delay`. A small amount of jitter is added to avoid reconnection storms when many
+    /// clients lose their connection to the same server at once.
+    #[must_use]
+    pub const fn with_reconnect_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.reconnect.base_delay = base_delay;
+        self.reconnect.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the maximum number of reconnection attempts after a connection loss.
+    ///
+    /// By default, the client retries indefinitely. Once the limit is reached, the background
+    /// task gives up and the client transitions to
+    /// [`ConnectionPhase::Failed`](crate::ConnectionPhase::Failed).
+    #[must_use]
+    pub const fn with_max_reconnect_attempts(mut self, max_attempts: u32) -> Self {
+        self.reconnect.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Connects to the given endpoint, consuming the builder.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the given endpoint URL is invalid or the connection attempt fails.
+    ///
+    /// # Panics
+    ///
+    /// This panics when the underlying client instance cannot be created (this should only happen
+    /// when the system runs out of memory).
+    pub fn connect(self, endpoint_url: &str) -> Result<crate::Client, Error> {
+        // SAFETY: `UA_Client_new()` returns either a valid pointer or `NULL`.
+        let client = unsafe { UA_Client_new() };
+        let client = ptr_to_ua_client(client);
+
+        let mut client = client;
+
+        // SAFETY: `client` is valid, freshly allocated, and not used elsewhere yet.
+        let status_code =
+            ua::StatusCode::new(unsafe { UA_ClientConfig_setDefault(ua::Client::config_mut(&mut client)) });
+        Error::verify_good(&status_code)?;
+
+        if let Some(security) = &self.security {
+            // SAFETY: `UA_ClientConfig_setDefaultEncryption()` expects certificate, private key, and
+            // trust list passed by value but copies them internally and does not take ownership.
+            let trust_list: Vec<_> = security
+                .trust_list
+                .iter()
+                .map(|certificate| unsafe { ua::ByteString::to_raw_copy(certificate) })
+                .collect();
+
+            let status_code = ua::StatusCode::new(unsafe {
+                UA_ClientConfig_setDefaultEncryption(
+                    ua::Client::config_mut(&mut client),
+                    ua::ByteString::to_raw_copy(&security.certificate),
+                    ua::ByteString::to_raw_copy(&security.private_key),
+                    trust_list.as_ptr(),
+                    trust_list.len(),
+                    std::ptr::null(),
+                    0,
+                )
+            });
+            Error::verify_good(&status_code)?;
+
+            ua::Client::config_mut(&mut client).securityMode = security.mode.as_raw();
+
+            let security_policy_uri: ua::String = security
+                .policy
+                .uri()
+                .parse()
+                .expect("security policy URI should be valid");
+            // SAFETY: We take ownership of the previous (default) policy URI set by
+            // `UA_ClientConfig_setDefault()` above, immediately drop it to free its buffer, and
+            // then hand ownership of the new one to the config.
+            unsafe {
+                let config = ua::Client::config_mut(&mut client);
+                drop(ua::String::from_raw(config.securityPolicyUri));
+                config.securityPolicyUri = ua::String::into_raw(security_policy_uri);
+            }
+        }
+
+        match &self.identity {
+            IdentityToken::Anonymous => {
+                // `UA_ClientConfig_setDefault()` already configures anonymous authentication.
+            }
+            IdentityToken::UserName { username, password } => {
+                let username = CString::new(username.as_str())
+                    .map_err(|_| Error::internal("username does not contain NUL bytes"))?;
+                let password = CString::new(password.as_str())
+                    .map_err(|_| Error::internal("password does not contain NUL bytes"))?;
+
+                // SAFETY: `client` is valid; the strings are copied internally and not used after
+                // this call returns.
+                let status_code = ua::StatusCode::new(unsafe {
+                    UA_ClientConfig_setAuthenticationUsername(
+                        ua::Client::config_mut(&mut client),
+                        username.as_ptr(),
+                        password.as_ptr(),
+                    )
+                });
+                Error::verify_good(&status_code)?;
+            }
+            IdentityToken::X509 { certificate } => {
+                let token = ua::X509IdentityToken::init().with_certificate_data(certificate);
+
+                // SAFETY: We take ownership of the raw value and hand it to the config, which takes
+                // over ownership of it from here on.
+                unsafe {
+                    ua::Client::config_mut(&mut client).userIdentityToken =
+                        ua::ExtensionObject::into_raw(ua::ExtensionObject::new(&token));
+                }
+            }
+        }
+
+        let endpoint_url_cstr = CString::new(endpoint_url)
+            .map_err(|_| Error::internal("endpoint URL does not contain NUL bytes"))?;
+
+        // SAFETY: `UA_Client_connect()` expects the endpoint URL as NUL-terminated string and does
+        // not take ownership of it.
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_connect(client.as_mut_ptr(), endpoint_url_cstr.as_ptr())
+        });
+        Error::verify_good(&status_code)?;
+
+        Ok(crate::Client::from_raw(client, self, endpoint_url.to_owned()))
+    }
+}
+
+pub(crate) fn ptr_to_ua_client(client: *mut open62541_sys::UA_Client) -> ua::Client {
+    // PANIC: We only reach here when the system is out of memory, which is not something we can
+    // reasonably recover from.
+    assert!(!client.is_null(), "should be able to create new client");
+
+    // SAFETY: `client` is a valid, owned `UA_Client` handle.
+    unsafe { ua::Client::from_raw(client) }
+}
+
+/// Connected OPC UA client (with synchronous API).
+///
+/// Use [`into_async()`](Self::into_async) to switch to the asynchronous API ([`AsyncClient`]),
+/// which is how almost all applications should use this crate.
+pub struct Client {
+    pub(crate) client: ua::Client,
+    pub(crate) builder: ClientBuilder,
+    pub(crate) endpoint_url: String,
+}
+
+impl Client {
+    pub(crate) fn from_raw(client: ua::Client, builder: ClientBuilder, endpoint_url: String) -> Self {
+        Self {
+            client,
+            builder,
+            endpoint_url,
+        }
+    }
+
+    /// Turns this into the asynchronous API.
+    ///
+    /// `cycle_time` is the interval at which the background task runs the event loop (see
+    /// [`AsyncClient`] for details).
+    #[must_use]
+    pub fn into_async(self, cycle_time: Duration) -> AsyncClient {
+        AsyncClient::from_sync(self.client, cycle_time, self.builder, self.endpoint_url)
+    }
+}
@@ -0,0 +1,133 @@
+use std::{ffi::CString, os::raw::c_void, ptr};
+
+use open62541_sys::{
+    UA_Array_delete, UA_Client_findServers, UA_Client_getEndpoints, UA_Client_new,
+    UA_EndpointDescription, UA_TYPES, UA_TYPES_APPLICATIONDESCRIPTION, UA_TYPES_ENDPOINTDESCRIPTION,
+};
+
+use crate::{
+    async_monitored_item::slice_from_raw, client_builder::ptr_to_ua_client, ua, DataType as _,
+    Error,
+};
+
+/// Discovers the servers available at `discovery_url`.
+///
+/// This performs only the `FindServers` discovery service call: it does not establish a session
+/// and requires no security configuration, matching the OPC UA discovery workflow of inspecting a
+/// server (or dedicated discovery server) before deciding how, or whether, to connect to it.
+///
+/// # Errors
+///
+/// This fails when the discovery URL is invalid or the discovery request fails.
+pub async fn find_servers(discovery_url: &str) -> Result<Vec<ua::ApplicationDescription>, Error> {
+    let discovery_url = CString::new(discovery_url)
+        .map_err(|_| Error::internal("discovery URL does not contain NUL bytes"))?;
+
+    // `UA_Client_findServers()` blocks on network I/O and has no asynchronous counterpart (unlike
+    // the session-bound services, which all run through the client's background event loop), so we
+    // run it on a blocking task instead.
+    tokio::task::spawn_blocking(move || find_servers_blocking(&discovery_url))
+        .await
+        .map_err(|_| Error::internal("discovery task panicked"))?
+}
+
+fn find_servers_blocking(discovery_url: &CString) -> Result<Vec<ua::ApplicationDescription>, Error> {
+    let mut client = ptr_to_ua_client(unsafe { UA_Client_new() });
+
+    let mut servers_size: usize = 0;
+    let mut servers: *mut open62541_sys::UA_ApplicationDescription = ptr::null_mut();
+
+    // SAFETY: `client` is valid and used by this call alone; `servers`/`servers_size` are
+    // out-parameters that `open62541` fills in on success.
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_Client_findServers(
+            client.as_mut_ptr(),
+            discovery_url.as_ptr(),
+            0,
+            ptr::null_mut(),
+            0,
+            ptr::null_mut(),
+            &mut servers_size,
+            &mut servers,
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    // SAFETY: `servers` is valid for `servers_size` elements, as guaranteed by the successful call
+    // above.
+    let result = unsafe { slice_from_raw(servers, servers_size) }
+        .iter()
+        .map(ua::ApplicationDescription::clone_raw)
+        .collect();
+
+    // SAFETY: `servers` was allocated by `open62541` for the call above and is no longer used
+    // afterwards; `result` above holds independent clones, not views into this array.
+    unsafe {
+        UA_Array_delete(
+            servers.cast::<c_void>(),
+            servers_size,
+            &UA_TYPES[UA_TYPES_APPLICATIONDESCRIPTION as usize],
+        );
+    }
+
+    Ok(result)
+}
+
+/// Discovers the endpoints offered by the server at `server_url`.
+///
+/// Each returned [`ua::EndpointDescription`] describes one way to connect, including its endpoint
+/// URL, security policy URI, security mode, and the server certificate (as a
+/// [`ua::ByteString`](crate::ByteString)). Inspect these to pick an endpoint (and matching
+/// [`SecurityPolicy`](crate::SecurityPolicy)) programmatically before connecting with
+/// [`ClientBuilder`](crate::ClientBuilder).
+///
+/// # Errors
+///
+/// This fails when the server URL is invalid or the discovery request fails.
+pub async fn get_endpoints(server_url: &str) -> Result<Vec<ua::EndpointDescription>, Error> {
+    let server_url = CString::new(server_url)
+        .map_err(|_| Error::internal("server URL does not contain NUL bytes"))?;
+
+    // See `find_servers()` for why this runs on a blocking task.
+    tokio::task::spawn_blocking(move || get_endpoints_blocking(&server_url))
+        .await
+        .map_err(|_| Error::internal("discovery task panicked"))?
+}
+
+fn get_endpoints_blocking(server_url: &CString) -> Result<Vec<ua::EndpointDescription>, Error> {
+    let mut client = ptr_to_ua_client(unsafe { UA_Client_new() });
+
+    let mut endpoints_size: usize = 0;
+    let mut endpoints: *mut UA_EndpointDescription = ptr::null_mut();
+
+    // SAFETY: `client` is valid and used by this call alone; `endpoints`/`endpoints_size` are
+    // out-parameters that `open62541` fills in on success.
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_Client_getEndpoints(
+            client.as_mut_ptr(),
+            server_url.as_ptr(),
+            &mut endpoints_size,
+            &mut endpoints,
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    // SAFETY: `endpoints` is valid for `endpoints_size` elements, as guaranteed by the successful
+    // call above.
+    let result = unsafe { slice_from_raw(endpoints, endpoints_size) }
+        .iter()
+        .map(ua::EndpointDescription::clone_raw)
+        .collect();
+
+    // SAFETY: `endpoints` was allocated by `open62541` for the call above and is no longer used
+    // afterwards; `result` above holds independent clones, not views into this array.
+    unsafe {
+        UA_Array_delete(
+            endpoints.cast::<c_void>(),
+            endpoints_size,
+            &UA_TYPES[UA_TYPES_ENDPOINTDESCRIPTION as usize],
+        );
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,87 @@
+//! Connection pooling for [`AsyncClient`], compatible with [`bb8`].
+//!
+//! Requires the `bb8` feature.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::{ua, AsyncClient, ClientBuilder, ConnectionPhase, Error};
+
+/// [`bb8::ManageConnection`] implementation that manages a pool of [`AsyncClient`] connections to
+/// a single OPC UA endpoint.
+///
+/// This lets services that would otherwise create and tear down [`AsyncClient`]s by hand instead
+/// check them out of a [`bb8::Pool`] for the duration of a request, sharing a bounded number of
+/// sessions across many concurrent tasks.
+#[derive(Debug, Clone)]
+pub struct ConnectionManager {
+    endpoint_url: String,
+    cycle_time: Duration,
+    builder: ClientBuilder,
+}
+
+impl ConnectionManager {
+    /// Creates a manager that connects to `endpoint_url` using the default [`ClientBuilder`].
+    ///
+    /// `cycle_time` is passed through to
+    /// [`Client::into_async()`](crate::Client::into_async) for every connection the pool creates.
+    /// Use [`Self::with_builder()`] to configure security or authentication instead.
+    #[must_use]
+    pub fn new(endpoint_url: impl Into<String>, cycle_time: Duration) -> Self {
+        Self::with_builder(endpoint_url, cycle_time, ClientBuilder::default())
+    }
+
+    /// Creates a manager that connects to `endpoint_url` using a pre-configured `builder`, e.g.
+    /// one set up with [`ClientBuilder::with_security()`].
+    #[must_use]
+    pub fn with_builder(
+        endpoint_url: impl Into<String>,
+        cycle_time: Duration,
+        builder: ClientBuilder,
+    ) -> Self {
+        Self {
+            endpoint_url: endpoint_url.into(),
+            cycle_time,
+            builder,
+        }
+    }
+}
+
+#[async_trait]
+impl bb8::ManageConnection for ConnectionManager {
+    type Connection = AsyncClient;
+    type Error = Error;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        // `ClientBuilder::connect()` blocks on network I/O for as long as the handshake takes (see
+        // the same reasoning in `async_client::reconnect_with_backoff()`), so run it on a blocking
+        // task instead of stalling whatever tokio worker thread `bb8` scheduled this on.
+        let builder = self.builder.clone();
+        let endpoint_url = self.endpoint_url.clone();
+        let client = tokio::task::spawn_blocking(move || builder.connect(&endpoint_url))
+            .await
+            .map_err(|_join_error| Error::internal("connect task panicked"))??;
+
+        Ok(client.into_async(self.cycle_time))
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        // `i=2258` is the well-known node ID of `Server_ServerStatus_CurrentTime`. Reading it is a
+        // cheap, side-effect-free way to confirm the session can still talk to the server.
+        let current_time = ua::NodeId::numeric(0, 2258);
+
+        conn.read_value(&current_time).await?;
+
+        Ok(())
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        // `Reconnecting` is deliberately not treated as broken: the background task recovers from
+        // it on its own (see `AsyncClient`'s reconnect supervisor), and `bb8` would otherwise evict
+        // and reconnect every pooled connection on the first transient disconnect instead of
+        // letting it recover in place. Only `Failed` (reconnection attempts exhausted) means this
+        // connection can never recover by itself.
+        matches!(*conn.state_changes().borrow(), ConnectionPhase::Failed)
+    }
+}
@@ -1,6 +1,6 @@
 use std::slice;
 
-use crate::ua;
+use crate::{ua, DataType as _};
 
 // Technically, `open62541_sys::ByteString` is an alias for `open62541_sys::String`. But we treat it
 // as a distinct type to improve type safety. The difference is that `String` contains valid Unicode
@@ -48,4 +48,40 @@ impl ByteString {
         // as regular arrays, i.e. empty and invalid states.
         ua::ArrayValue::from_ptr(self.0.data)
     }
+
+    /// Creates byte string by copying the given bytes.
+    ///
+    /// This is used e.g. to load application certificates and private keys (supplied as DER
+    /// bytes) into [`ClientBuilder`](crate::ClientBuilder).
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut inner = Self::init().0;
+
+        if !bytes.is_empty() {
+            // SAFETY: `inner` is a freshly initialized, empty `UA_ByteString` that we own
+            // exclusively.
+            let status_code =
+                unsafe { open62541_sys::UA_ByteString_allocBuffer(&mut inner, bytes.len()) };
+            assert!(
+                ua::StatusCode::new(status_code).is_good(),
+                "should be able to allocate byte string buffer"
+            );
+
+            // SAFETY: `inner.data` was just allocated above with `bytes.len()` capacity and is not
+            // aliased by anything else.
+            unsafe {
+                std::ptr::copy_nonoverlapping(bytes.as_ptr(), inner.data, bytes.len());
+            }
+        }
+
+        Self(inner)
+    }
+}
+
+impl TryFrom<Vec<u8>> for ByteString {
+    type Error = std::convert::Infallible;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self::from_bytes(&bytes))
+    }
 }